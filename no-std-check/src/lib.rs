@@ -0,0 +1,64 @@
+//! Exercises `usage` from a `#![no_std]` crate.
+//!
+//! Build on its own so the root crate's default features aren't unified in:
+//! ```text
+//! cargo build -p usage-no-std-check
+//! cargo build -p usage-no-std-check --features alloc
+//! ```
+
+#![no_std]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+use core::fmt::{self, Write};
+use usage::{AsUsage, IntoUsage, Usage};
+
+pub enum Celsius {}
+
+pub type Temperature = Usage<Celsius, i16>;
+
+pub fn warm(temp: Temperature, delta: i16) -> Temperature {
+    temp + delta
+}
+
+pub fn format_into<W: Write>(out: &mut W, temp: &Temperature) -> fmt::Result {
+    write!(out, "{}", temp)
+}
+
+#[derive(Debug)]
+pub struct SensorFault;
+
+impl fmt::Display for SensorFault {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("sensor fault")
+    }
+}
+
+impl core::error::Error for SensorFault {}
+
+pub fn read(raw: Option<i16>) -> Result<Temperature, Usage<Celsius, SensorFault>> {
+    raw.map(Celsius::as_usage)
+        .ok_or(SensorFault.usage::<Celsius>())
+}
+
+pub fn as_error(fault: &Usage<Celsius, SensorFault>) -> &dyn core::error::Error {
+    fault
+}
+
+#[cfg(feature = "alloc")]
+pub fn readings(raw: &[i16]) -> Usage<Celsius, alloc::vec::Vec<i16>> {
+    raw.iter().copied().collect()
+}
+
+#[cfg(feature = "alloc")]
+pub fn label(name: &str) -> Usage<Celsius, alloc::string::String> {
+    let mut label = Celsius::as_usage(alloc::string::String::from(name));
+    label.push_str(" (C)");
+    label
+}
+
+#[cfg(feature = "alloc")]
+pub fn label_str(label: &Usage<Celsius, alloc::string::String>) -> &str {
+    AsRef::<str>::as_ref(label)
+}
@@ -14,6 +14,45 @@ pub trait AsUsage: Sized {
             _phantom: Default::default(),
         }
     }
+
+    /// Construct a [`Usage<Self, T>`] from the success value of a [`Result`]
+    /// ```rust
+    /// use usage::{Usage, AsUsage};
+    ///
+    /// pub enum Port {}
+    /// let port: Result<Usage<Port, u16>, _> = Port::try_as_usage("8080".parse::<u16>());
+    /// assert_eq!(port.unwrap().data, 8080);
+    ///
+    /// let port = Port::try_as_usage("http".parse::<u16>());
+    /// assert!(port.is_err());
+    /// ```
+    fn try_as_usage<T, E>(res: Result<T, E>) -> Result<Usage<Self, T>, E> {
+        res.map(Self::as_usage)
+    }
+
+    /// Construct a [`Usage<Self, T>`] from the return value of a closure
+    /// ```rust
+    /// use usage::{Usage, AsUsage};
+    ///
+    /// pub enum Contrived {}
+    /// let contrived: Usage<Contrived, Vec<usize>> = Contrived::as_usage_with(|| (0..3).collect());
+    /// assert_eq!(contrived.data, [0, 1, 2]);
+    /// ```
+    fn as_usage_with<T>(f: impl FnOnce() -> T) -> Usage<Self, T> {
+        Self::as_usage(f())
+    }
+
+    /// Construct a [`Usage<Self, T>`] from the default value of `T`
+    /// ```rust
+    /// use usage::{Usage, AsUsage};
+    ///
+    /// pub enum Contrived {}
+    /// let contrived: Usage<Contrived, Vec<usize>> = Contrived::as_usage_default();
+    /// assert!(contrived.is_empty());
+    /// ```
+    fn as_usage_default<T: Default>() -> Usage<Self, T> {
+        Self::as_usage(Default::default())
+    }
 }
 
 impl<T> AsUsage for T {}
@@ -0,0 +1,22 @@
+use super::Usage;
+
+/// Utility trait for constructing a [`Usage<U, T>`] from its data
+/// ```rust
+/// use usage::{Usage, IntoUsage};
+///
+/// pub enum Ids {}
+/// let ids: Usage<Ids, Vec<usize>> = vec![1, 2, 3].into_usage::<Ids>();
+/// let ids: Usage<Ids, Vec<usize>> = vec![1, 2, 3].usage::<Ids>();
+/// ```
+pub trait IntoUsage: Sized {
+    fn into_usage<U>(self) -> Usage<U, Self> {
+        Usage::new(self)
+    }
+
+    /// Alias for [`IntoUsage::into_usage`]
+    fn usage<U>(self) -> Usage<U, Self> {
+        self.into_usage()
+    }
+}
+
+impl<T> IntoUsage for T {}
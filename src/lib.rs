@@ -60,7 +60,10 @@
 //! It does this by using two generic parameters: Type `U` to act as a tag identifying it as a
 //! distinct type, and type `T` for underlying data.
 //!
-//! `U` is represented by a [`PhantomData`], thus decoupling its trait implementations from those of the `Usage`.
+//! `U` is represented by a [`PhantomData`] over `fn(U) -> U`, thus decoupling its trait implementations
+//! (including auto traits like [`Send`] and [`Sync`]) from those of the `Usage`.
+//! This also makes `Usage` invariant over `U` and ensures the tag has no drop check implications,
+//! so it acts purely as a marker.
 //!
 //! Construction and access trait implementations are predicated on `T`, allowing the `Usage` to
 //! transparently act like its underlying type in as many contexts as possible.
@@ -78,14 +81,75 @@
 //! feel free to send a pull request with the new functionality gated behind a feature flag
 //! as per the existing `rayon` and `bytemuck` implementations.
 //!
+//! Some foreign traits are sealed outright. `tracing::Value` is one such trait, so tagged fields
+//! are recorded through their inner value instead: with the `%`/`?` sigils via the forwarding
+//! `Display`/`Debug` impls, by dereferencing (`id = *id`) to record `T` directly,
+//! or via `tracing::field::valuable(&id)` with the `valuable` feature
+//! (which `tracing` gates behind `--cfg tracing_unstable`).
+//!
+//! Others are blanket-implemented and need no feature at all: `nalgebra`'s `Scalar` and simba's
+//! `ClosedAdd`/`ClosedMul` family already cover `Usage<U, T>` wherever `T` qualifies, through the
+//! forwarding operator, `Debug` and `PartialEq` impls. Enabling `num-traits` adds the `Zero`/`One`
+//! bounds needed by constructors like `zeros` and by products.
+//!
+//! ## `no_std`
+//!
+//! The crate is `no_std` when its default `std` feature is disabled.
+//! The `alloc` feature restores passthrough impls for `String`, `Vec`, `Box` and friends,
+//! while `std` additionally covers `Path`, `OsStr` and the `std::io` traits.
+//! ```
+//! #![no_std]
+//! # extern crate std;
+//!
+//! use core::fmt::Write;
+//! use usage::Usage;
+//!
+//! enum Celsius {}
+//!
+//! struct Buf([u8; 8], usize);
+//!
+//! impl Write for Buf {
+//!     fn write_str(&mut self, s: &str) -> core::fmt::Result {
+//!         self.0[self.1..self.1 + s.len()].copy_from_slice(s.as_bytes());
+//!         self.1 += s.len();
+//!         Ok(())
+//!     }
+//! }
+//!
+//! fn main() {
+//!     let temp = Usage::<Celsius, i16>::new(-40);
+//!     let mut buf = Buf([0; 8], 0);
+//!     write!(buf, "{}", temp).unwrap();
+//!
+//!     assert_eq!(&buf.0[..buf.1], b"-40");
+//!     assert_eq!(temp + 60, Usage::<Celsius, i16>::new(20));
+//! }
+//! ```
+
+#![cfg_attr(not(feature = "std"), no_std)]
 
 mod as_usage;
+mod into_usage;
+mod macros;
 pub use as_usage::*;
+pub use into_usage::*;
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+#[cfg(feature = "alloc")]
+use alloc::{boxed::Box, string::String, vec::Vec};
 
-use std::{
-    borrow::{Borrow, BorrowMut},
+#[cfg(any(doc, not(feature = "tagged-hash")))]
+use core::borrow::{Borrow, BorrowMut};
+use core::{
+    iter::{Product, Sum},
     marker::PhantomData,
-    ops::{Deref, DerefMut},
+    mem::ManuallyDrop,
+    ops::{Deref, DerefMut, Index, IndexMut},
+    panic::{RefUnwindSafe, UnwindSafe},
+    pin::Pin,
+    str::FromStr,
 };
 
 /// Wrapper type for creating a transparent-yet-distinct type over some underlying data.
@@ -102,27 +166,123 @@ use std::{
 /// type SurfaceSize = Usage<Surface, Size>;
 /// type TextureSize = Usage<Texture, Size>;
 /// ```
+///
+/// `Usage` is `#[repr(transparent)]`, so it is guaranteed to have the same layout as `T`:
+/// ```
+/// use usage::Usage;
+///
+/// enum Tag {}
+///
+/// const _: () = assert!(std::mem::size_of::<Usage<Tag, u64>>() == std::mem::size_of::<u64>());
+/// const _: () = assert!(std::mem::align_of::<Usage<Tag, u64>>() == std::mem::align_of::<u64>());
+/// const _: () = assert!(std::mem::size_of::<Usage<Tag, Vec<u8>>>() == std::mem::size_of::<Vec<u8>>());
+/// const _: () = assert!(std::mem::align_of::<Usage<Tag, [u8; 3]>>() == std::mem::align_of::<[u8; 3]>());
+/// ```
+///
+/// Auto traits like [`Send`] and [`Sync`] depend only on `T`, since no value of `U` is ever stored:
+/// ```
+/// use usage::Usage;
+///
+/// struct NotSend(*const ());
+///
+/// fn assert_send_sync<T: Send + Sync>() {}
+///
+/// assert_send_sync::<Usage<NotSend, i32>>();
+/// assert_send_sync::<Usage<NotSend, Vec<u8>>>();
+/// ```
+///
+/// `Usage` is invariant over `U`, so distinct tags never unify:
+/// ```compile_fail
+/// use usage::Usage;
+///
+/// enum Window {}
+/// enum Surface {}
+///
+/// let window_size: Usage<Window, (u32, u32)> = Usage::new((640, 480));
+/// let surface_size: Usage<Surface, (u32, u32)> = window_size;
+/// ```
+///
+/// Including lifetime tags, which would otherwise be subject to subtyping:
+/// ```compile_fail
+/// use usage::Usage;
+///
+/// fn shorten<'a>(usage: Usage<&'static (), u32>) -> Usage<&'a (), u32> {
+///     usage
+/// }
+/// ```
+///
+/// By default, [`Debug`](std::fmt::Debug) formats as a struct including the tag's type name.
+///
+/// With the `compact-debug` feature enabled, forwards to `T` instead,
+/// producing output identical to the inner type:
+/// ```
+/// use usage::Usage;
+///
+/// enum Tag {}
+///
+/// let usage = Usage::<Tag, Vec<u32>>::new(vec![1, 2]);
+///
+/// if cfg!(feature = "compact-debug") {
+///     assert_eq!(format!("{:?}", usage), format!("{:?}", vec![1, 2]));
+///     assert_eq!(format!("{:#?}", usage), format!("{:#?}", vec![1, 2]));
+/// } else {
+///     assert_eq!(
+///         format!("{:?}", usage),
+///         format!(
+///             "Usage {{ data: [1, 2], _phantom: \"PhantomData<{}>\" }}",
+///             std::any::type_name::<Tag>()
+///         )
+///     );
+///     assert_eq!(
+///         format!("{:#?}", usage),
+///         format!(
+///             "Usage {{\n    data: [\n        1,\n        2,\n    ],\n    _phantom: \"PhantomData<{}>\",\n}}",
+///             std::any::type_name::<Tag>()
+///         )
+///     );
+/// }
+/// ```
+#[repr(transparent)]
 pub struct Usage<U, T> {
     pub data: T,
-    _phantom: PhantomData<U>,
+    _phantom: PhantomData<fn(U) -> U>,
 }
 
 // Derived traits
-impl<U, T> std::fmt::Debug for Usage<U, T>
+#[cfg(not(feature = "compact-debug"))]
+impl<U, T> core::fmt::Debug for Usage<U, T>
 where
-    T: std::fmt::Debug,
+    T: core::fmt::Debug,
 {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_struct("Usage")
             .field("data", &self.data)
-            .field(
-                "_phantom",
-                &format!("PhantomData<{}>", std::any::type_name::<U>()),
-            )
+            .field("_phantom", &PhantomName::<U>(PhantomData))
             .finish()
     }
 }
 
+/// Allocation-free stand-in for the quoted `"PhantomData<U>"` string used in [`Usage`]'s `Debug` output
+#[cfg(not(feature = "compact-debug"))]
+struct PhantomName<U>(PhantomData<fn(U) -> U>);
+
+#[cfg(not(feature = "compact-debug"))]
+impl<U> core::fmt::Debug for PhantomName<U> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "\"PhantomData<{}>\"", core::any::type_name::<U>())
+    }
+}
+
+#[cfg(feature = "compact-debug")]
+impl<U, T> core::fmt::Debug for Usage<U, T>
+where
+    T: core::fmt::Debug,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        core::fmt::Debug::fmt(&self.data, f)
+    }
+}
+
 impl<U, T> Default for Usage<U, T>
 where
     T: Default,
@@ -149,6 +309,7 @@ where
     }
 }
 
+#[cfg(not(feature = "cross-tag-cmp"))]
 impl<U, T> PartialEq for Usage<U, T>
 where
     T: PartialEq,
@@ -158,20 +319,52 @@ where
     }
 }
 
-impl<U, T> Eq for Usage<U, T>
+/// With the `cross-tag-cmp` feature enabled, `Usage`s with different tags
+/// may be compared on their data.
+///
+/// This trades away some of the distinction between tags for convenience,
+/// and as with any feature, applies to all crates in the dependency graph once enabled.
+/// ```
+/// use usage::Usage;
+///
+/// enum A {}
+/// enum B {}
+///
+/// let a = Usage::<A, i32>::new(1);
+/// let b = Usage::<B, i32>::new(2);
+///
+/// assert!(a != b);
+/// assert!(a < b);
+/// assert!(a == Usage::<B, i32>::new(1));
+/// ```
+#[cfg(feature = "cross-tag-cmp")]
+impl<UA, UB, T> PartialEq<Usage<UB, T>> for Usage<UA, T>
 where
-    T: Eq,
+    T: PartialEq,
 {
-    fn assert_receiver_is_total_eq(&self) {
-        self.data.assert_receiver_is_total_eq()
+    fn eq(&self, other: &Usage<UB, T>) -> bool {
+        self.data.eq(&other.data)
     }
 }
 
+impl<U, T> Eq for Usage<U, T> where T: Eq {}
+
+#[cfg(not(feature = "cross-tag-cmp"))]
 impl<U, T> PartialOrd for Usage<U, T>
 where
     T: PartialOrd,
 {
-    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        self.data.partial_cmp(&other.data)
+    }
+}
+
+#[cfg(feature = "cross-tag-cmp")]
+impl<UA, UB, T> PartialOrd<Usage<UB, T>> for Usage<UA, T>
+where
+    T: PartialOrd,
+{
+    fn partial_cmp(&self, other: &Usage<UB, T>) -> Option<core::cmp::Ordering> {
         self.data.partial_cmp(&other.data)
     }
 }
@@ -180,109 +373,5034 @@ impl<U, T> Ord for Usage<U, T>
 where
     T: Ord,
 {
-    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
         self.data.cmp(&other.data)
     }
 }
 
-impl<U, T> std::hash::Hash for Usage<U, T>
+/// `Usage<U, T>` may also be compared directly against a bare `T`:
+/// ```
+/// use usage::Usage;
+///
+/// enum Tag {}
+///
+/// let usage = Usage::<Tag, i32>::new(5);
+/// assert!(usage == 5);
+/// assert!(usage != 6);
+/// assert!(usage < 10);
+/// assert!(usage >= 5);
+/// assert_eq!(usage.partial_cmp(&3), Some(std::cmp::Ordering::Greater));
+/// ```
+impl<U, T> PartialEq<T> for Usage<U, T>
 where
-    T: std::hash::Hash,
+    T: PartialEq,
 {
-    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
-        self.data.hash(state)
+    fn eq(&self, other: &T) -> bool {
+        self.data.eq(other)
     }
 }
 
-// Construction traits
-impl<U, T> From<T> for Usage<U, T> {
-    fn from(t: T) -> Self {
-        U::as_usage(t)
+impl<U, T> PartialOrd<T> for Usage<U, T>
+where
+    T: PartialOrd,
+{
+    fn partial_cmp(&self, other: &T) -> Option<core::cmp::Ordering> {
+        self.data.partial_cmp(other)
     }
 }
 
-impl<U, T, V> FromIterator<V> for Usage<U, T>
+#[cfg(not(feature = "tagged-hash"))]
+impl<U, T> core::hash::Hash for Usage<U, T>
 where
-    T: FromIterator<V>,
+    T: core::hash::Hash,
 {
-    fn from_iter<I: IntoIterator<Item = V>>(iter: I) -> Self {
-        U::as_usage(iter.into_iter().collect())
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.data.hash(state)
     }
 }
 
-#[cfg(feature = "rayon")]
-mod rayon_impl {
-    use super::*;
-    use rayon::iter::{
-        FromParallelIterator, IntoParallelIterator, ParallelExtend, ParallelIterator,
+/// With the `tagged-hash` feature enabled, the [`TypeId`](std::any::TypeId) of `U`
+/// is hashed before `T`, so equal data under distinct tags produces distinct hashes.
+///
+/// A `Usage` then no longer hashes identically to `T` or its borrowed forms,
+/// so the `Borrow` impls are left out to uphold `Borrow`'s hashing contract.
+/// ```
+/// use std::hash::{BuildHasher, RandomState};
+/// use usage::Usage;
+///
+/// enum Window {}
+/// enum Surface {}
+///
+/// let state = RandomState::new();
+/// let window = state.hash_one(Usage::<Window, u32>::new(1));
+/// let surface = state.hash_one(Usage::<Surface, u32>::new(1));
+///
+/// assert_ne!(window, surface);
+/// assert_eq!(window, state.hash_one(Usage::<Window, u32>::new(1)));
+/// ```
+///
+/// Hashed collections are keyed by whole `Usage`s, and equal keys hash equally:
+/// ```
+/// use std::collections::HashMap;
+/// use std::hash::{BuildHasher, RandomState};
+/// use usage::Usage;
+///
+/// enum Key {}
+///
+/// let state = RandomState::new();
+/// let foo = Usage::<Key, String>::new("foo".into());
+/// assert_eq!(state.hash_one(&foo), state.hash_one(Usage::<Key, String>::new("foo".into())));
+///
+/// let mut map = HashMap::<Usage<Key, String>, i32>::new();
+/// map.insert(foo.clone(), 1);
+/// assert_eq!(map.get(&foo), Some(&1));
+/// assert_eq!(map.get(&Usage::new("bar".into())), None);
+/// ```
+///
+/// Lookups by a borrowed form would hash differently, so they don't compile:
+/// ```compile_fail
+/// use std::collections::HashMap;
+/// use usage::Usage;
+///
+/// enum Key {}
+///
+/// let map = HashMap::<Usage<Key, String>, i32>::new();
+/// map.get("foo");
+/// ```
+#[cfg(feature = "tagged-hash")]
+impl<U, T> core::hash::Hash for Usage<U, T>
+where
+    U: 'static,
+    T: core::hash::Hash,
+{
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        core::any::TypeId::of::<U>().hash(state);
+        self.data.hash(state)
+    }
+}
+
+macro_rules! impl_hasher_writes {
+    ($($method:ident: $ty:ty),* $(,)?) => {
+        $(
+            fn $method(&mut self, i: $ty) {
+                self.data.$method(i)
+            }
+        )*
     };
+}
 
-    impl<U, T, V> FromParallelIterator<V> for Usage<U, T>
-    where
-        T: FromParallelIterator<V>,
-        V: Send,
-    {
-        fn from_par_iter<I: rayon::iter::IntoParallelIterator<Item = V>>(par_iter: I) -> Self {
-            U::as_usage(par_iter.into_par_iter().collect())
-        }
+/// Forwards to `T` when it is itself a hasher, including its specialized integer writes:
+/// ```
+/// use std::collections::hash_map::DefaultHasher;
+/// use std::hash::{Hash, Hasher};
+/// use usage::Usage;
+///
+/// enum Stable {}
+///
+/// let mut tagged = Usage::<Stable, DefaultHasher>::new(DefaultHasher::new());
+/// let mut plain = DefaultHasher::new();
+///
+/// "ferris".hash(&mut tagged);
+/// 42u64.hash(&mut tagged);
+///
+/// "ferris".hash(&mut plain);
+/// 42u64.hash(&mut plain);
+///
+/// assert_eq!(tagged.finish(), plain.finish());
+/// ```
+impl<U, T> core::hash::Hasher for Usage<U, T>
+where
+    T: core::hash::Hasher,
+{
+    fn finish(&self) -> u64 {
+        self.data.finish()
     }
 
-    impl<U, T, V> ParallelExtend<V> for Usage<U, T>
-    where
-        T: ParallelExtend<V>,
-        V: Send,
-    {
-        fn par_extend<I: IntoParallelIterator<Item = V>>(&mut self, par_iter: I) {
-            self.data.par_extend(par_iter)
-        }
+    fn write(&mut self, bytes: &[u8]) {
+        self.data.write(bytes)
     }
+
+    impl_hasher_writes!(
+        write_u8: u8,
+        write_u16: u16,
+        write_u32: u32,
+        write_u64: u64,
+        write_u128: u128,
+        write_usize: usize,
+        write_i8: i8,
+        write_i16: i16,
+        write_i32: i32,
+        write_i64: i64,
+        write_i128: i128,
+        write_isize: isize,
+    );
 }
 
-#[cfg(feature = "bytemuck")]
-mod bytemuck_impl {
-    use super::*;
-    use bytemuck::{Pod, Zeroable};
+/// Builds the hasher of `T`, so a tagged hasher state can parameterize hashed collections:
+/// ```
+/// use std::collections::HashMap;
+/// use std::hash::{BuildHasher, RandomState};
+/// use usage::Usage;
+///
+/// enum Sessions {}
+///
+/// let state = Usage::<Sessions, RandomState>::new(RandomState::new());
+/// assert_eq!(state.hash_one("key"), state.data.hash_one("key"));
+///
+/// let mut map = HashMap::with_hasher(state);
+/// map.insert("key", 1);
+/// assert_eq!(map.get("key"), Some(&1));
+/// ```
+impl<U, T> core::hash::BuildHasher for Usage<U, T>
+where
+    T: core::hash::BuildHasher,
+{
+    type Hasher = T::Hasher;
 
-    unsafe impl<U, T> Zeroable for Usage<U, T> where T: Zeroable {}
+    fn build_hasher(&self) -> Self::Hasher {
+        self.data.build_hasher()
+    }
+}
 
-    unsafe impl<U, T> Pod for Usage<U, T>
-    where
-        U: 'static,
-        T: Pod,
-    {
+// Formatting traits
+/// Forwards to `T`, honoring formatter flags:
+/// ```
+/// use usage::Usage;
+///
+/// enum Celsius {}
+///
+/// let temperature = Usage::<Celsius, f64>::new(21.456);
+/// assert_eq!(format!("{}", temperature), format!("{}", 21.456));
+/// assert_eq!(format!("{:>8.2}", temperature), format!("{:>8.2}", 21.456));
+/// assert_eq!(format!("{:*<8.1}", temperature), "21.5****");
+/// ```
+impl<U, T> core::fmt::Display for Usage<U, T>
+where
+    T: core::fmt::Display,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        self.data.fmt(f)
     }
 }
 
-// Data access traits
-impl<U, T> Borrow<T> for Usage<U, T> {
-    fn borrow(&self) -> &T {
-        &self.data
+/// Forwards to `T`, honoring formatter flags:
+/// ```
+/// use usage::Usage;
+///
+/// enum RegisterAddr {}
+///
+/// let addr = Usage::<RegisterAddr, u32>::new(0x2a);
+/// assert_eq!(format!("{:#06x}", addr), format!("{:#06x}", 0x2a_u32));
+/// assert_eq!(format!("{:#06X}", addr), format!("{:#06X}", 0x2a_u32));
+/// assert_eq!(format!("{:#010b}", addr), format!("{:#010b}", 0x2a_u32));
+/// assert_eq!(format!("{:#o}", addr), format!("{:#o}", 0x2a_u32));
+/// assert_eq!(format!("{:x}", addr), "2a");
+/// ```
+impl<U, T> core::fmt::LowerHex for Usage<U, T>
+where
+    T: core::fmt::LowerHex,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        core::fmt::LowerHex::fmt(&self.data, f)
     }
 }
 
-impl<U, T> BorrowMut<T> for Usage<U, T> {
-    fn borrow_mut(&mut self) -> &mut T {
-        &mut self.data
+impl<U, T> core::fmt::UpperHex for Usage<U, T>
+where
+    T: core::fmt::UpperHex,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        core::fmt::UpperHex::fmt(&self.data, f)
     }
 }
 
-impl<U, T> Deref for Usage<U, T> {
-    type Target = T;
+impl<U, T> core::fmt::Binary for Usage<U, T>
+where
+    T: core::fmt::Binary,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        core::fmt::Binary::fmt(&self.data, f)
+    }
+}
 
-    fn deref(&self) -> &Self::Target {
-        &self.data
+impl<U, T> core::fmt::Octal for Usage<U, T>
+where
+    T: core::fmt::Octal,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        core::fmt::Octal::fmt(&self.data, f)
     }
 }
 
-impl<U, T> DerefMut for Usage<U, T> {
-    fn deref_mut(&mut self) -> &mut Self::Target {
-        &mut self.data
+/// Forwards to `T`, honoring formatter flags:
+/// ```
+/// use usage::Usage;
+///
+/// enum Voltage {}
+///
+/// let voltage = Usage::<Voltage, f64>::new(1234.5678);
+/// assert_eq!(format!("{:.3e}", voltage), format!("{:.3e}", 1234.5678));
+/// assert_eq!(format!("{:.3E}", voltage), format!("{:.3E}", 1234.5678));
+/// assert_eq!(format!("{:>12e}", voltage), format!("{:>12e}", 1234.5678));
+/// ```
+impl<U, T> core::fmt::LowerExp for Usage<U, T>
+where
+    T: core::fmt::LowerExp,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        core::fmt::LowerExp::fmt(&self.data, f)
     }
 }
 
-impl<U, T> Usage<U, T> {
-    /// Convert `Usage<T>` into `T` by value
-    pub fn into_inner(self) -> T {
+impl<U, T> core::fmt::UpperExp for Usage<U, T>
+where
+    T: core::fmt::UpperExp,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        core::fmt::UpperExp::fmt(&self.data, f)
+    }
+}
+
+/// Forwards to `T`, allowing pointer-like data to be formatted with `{:p}`:
+/// ```
+/// use usage::Usage;
+///
+/// enum Handle {}
+///
+/// let value = 42_u32;
+/// let handle = Usage::<Handle, &u32>::new(&value);
+/// assert_eq!(format!("{:p}", handle), format!("{:p}", &value));
+///
+/// let ptr = Usage::<Handle, *const u32>::new(&value);
+/// assert_eq!(format!("{:p}", ptr), format!("{:p}", &value as *const u32));
+/// ```
+impl<U, T> core::fmt::Pointer for Usage<U, T>
+where
+    T: core::fmt::Pointer,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        core::fmt::Pointer::fmt(&self.data, f)
+    }
+}
+
+/// Forwards to `T`, allowing use with [`write!`]:
+/// ```
+/// use std::fmt::Write;
+/// use usage::Usage;
+///
+/// enum Report {}
+///
+/// let mut report = Usage::<Report, String>::new(String::new());
+///
+/// write!(report, "{} passed", 3).unwrap();
+/// report.write_char(',').unwrap();
+/// writeln!(report, " {} failed", 0).unwrap();
+///
+/// assert_eq!(report.data, "3 passed, 0 failed\n");
+/// ```
+impl<U, T> core::fmt::Write for Usage<U, T>
+where
+    T: core::fmt::Write,
+{
+    fn write_str(&mut self, s: &str) -> core::fmt::Result {
+        self.data.write_str(s)
+    }
+
+    fn write_char(&mut self, c: char) -> core::fmt::Result {
+        self.data.write_char(c)
+    }
+
+    fn write_fmt(&mut self, args: core::fmt::Arguments<'_>) -> core::fmt::Result {
+        self.data.write_fmt(args)
+    }
+}
+
+// Error traits
+/// Forwards to `T`, preserving its `source()` chain:
+/// ```
+/// use std::error::Error;
+/// use usage::Usage;
+///
+/// #[derive(Debug)]
+/// struct ConnectionLost;
+///
+/// impl std::fmt::Display for ConnectionLost {
+///     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+///         write!(f, "connection lost")
+///     }
+/// }
+///
+/// impl Error for ConnectionLost {}
+///
+/// #[derive(Debug)]
+/// struct QueryFailed(ConnectionLost);
+///
+/// impl std::fmt::Display for QueryFailed {
+///     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+///         write!(f, "query failed")
+///     }
+/// }
+///
+/// impl Error for QueryFailed {
+///     fn source(&self) -> Option<&(dyn Error + 'static)> {
+///         Some(&self.0)
+///     }
+/// }
+///
+/// enum DbLayer {}
+///
+/// fn query() -> Result<(), Usage<DbLayer, QueryFailed>> {
+///     Err(Usage::new(QueryFailed(ConnectionLost)))
+/// }
+///
+/// fn run() -> Result<(), Box<dyn Error>> {
+///     query()?;
+///     Ok(())
+/// }
+///
+/// let err = run().unwrap_err();
+/// assert_eq!(err.to_string(), "query failed");
+/// assert_eq!(err.source().unwrap().to_string(), "connection lost");
+/// assert!(err.source().unwrap().source().is_none());
+/// ```
+impl<U, T> core::error::Error for Usage<U, T>
+where
+    T: core::error::Error,
+{
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        self.data.source()
+    }
+
+    #[allow(deprecated)]
+    fn description(&self) -> &str {
+        self.data.description()
+    }
+
+    #[allow(deprecated)]
+    fn cause(&self) -> Option<&dyn core::error::Error> {
+        self.data.cause()
+    }
+}
+
+// I/O traits
+/// Reads from `T`:
+/// ```
+/// use std::io::{Cursor, Read};
+/// use usage::Usage;
+///
+/// enum Payload {}
+///
+/// let mut reader = Usage::<Payload, _>::new(Cursor::new(vec![1, 2, 3, 4, 5]));
+///
+/// let mut buf = [0; 2];
+/// assert_eq!(reader.read(&mut buf).unwrap(), 2);
+/// assert_eq!(buf, [1, 2]);
+///
+/// reader.read_exact(&mut buf).unwrap();
+/// assert_eq!(buf, [3, 4]);
+///
+/// let mut rest = vec![];
+/// reader.read_to_end(&mut rest).unwrap();
+/// assert_eq!(rest, [5]);
+/// ```
+#[cfg(feature = "std")]
+impl<U, T> std::io::Read for Usage<U, T>
+where
+    T: std::io::Read,
+{
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        self.data.read(buf)
+    }
+
+    fn read_vectored(&mut self, bufs: &mut [std::io::IoSliceMut<'_>]) -> std::io::Result<usize> {
+        self.data.read_vectored(bufs)
+    }
+
+    fn read_to_end(&mut self, buf: &mut Vec<u8>) -> std::io::Result<usize> {
+        self.data.read_to_end(buf)
+    }
+
+    fn read_to_string(&mut self, buf: &mut String) -> std::io::Result<usize> {
+        self.data.read_to_string(buf)
+    }
+
+    fn read_exact(&mut self, buf: &mut [u8]) -> std::io::Result<()> {
+        self.data.read_exact(buf)
+    }
+}
+
+/// Writes to `T`:
+/// ```
+/// use std::io::{Cursor, Write};
+/// use usage::Usage;
+///
+/// enum LogSink {}
+///
+/// let mut sink = Usage::<LogSink, _>::new(Cursor::new(Vec::new()));
+///
+/// assert_eq!(sink.write(b"hello").unwrap(), 5);
+/// sink.write_all(b", world").unwrap();
+/// writeln!(sink, "!").unwrap();
+/// sink.flush().unwrap();
+///
+/// assert_eq!(sink.data.into_inner(), b"hello, world!\n");
+/// ```
+#[cfg(feature = "std")]
+impl<U, T> std::io::Write for Usage<U, T>
+where
+    T: std::io::Write,
+{
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.data.write(buf)
+    }
+
+    fn write_vectored(&mut self, bufs: &[std::io::IoSlice<'_>]) -> std::io::Result<usize> {
+        self.data.write_vectored(bufs)
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        self.data.flush()
+    }
+
+    fn write_all(&mut self, buf: &[u8]) -> std::io::Result<()> {
+        self.data.write_all(buf)
+    }
+}
+
+/// Seeks within `T`:
+/// ```
+/// use std::io::{Cursor, Read, Seek, SeekFrom};
+/// use usage::Usage;
+///
+/// enum Payload {}
+///
+/// let mut reader = Usage::<Payload, _>::new(Cursor::new(b"0123456789".to_vec()));
+///
+/// assert_eq!(reader.seek(SeekFrom::Start(4)).unwrap(), 4);
+/// assert_eq!(reader.seek(SeekFrom::Current(2)).unwrap(), 6);
+/// assert_eq!(reader.stream_position().unwrap(), 6);
+///
+/// let mut buf = [0; 2];
+/// reader.read_exact(&mut buf).unwrap();
+/// assert_eq!(&buf, b"67");
+///
+/// assert_eq!(reader.seek(SeekFrom::End(-1)).unwrap(), 9);
+/// ```
+#[cfg(feature = "std")]
+impl<U, T> std::io::Seek for Usage<U, T>
+where
+    T: std::io::Seek,
+{
+    fn seek(&mut self, pos: std::io::SeekFrom) -> std::io::Result<u64> {
+        self.data.seek(pos)
+    }
+
+    fn stream_position(&mut self) -> std::io::Result<u64> {
+        self.data.stream_position()
+    }
+}
+
+/// Reads from the buffer of `T`:
+/// ```
+/// use std::io::{BufRead, Cursor};
+/// use usage::Usage;
+///
+/// enum Config {}
+///
+/// let mut reader = Usage::<Config, _>::new(Cursor::new("name = ferris\nage = 9\n"));
+///
+/// let mut line = String::new();
+/// reader.read_line(&mut line).unwrap();
+/// assert_eq!(line, "name = ferris\n");
+///
+/// assert_eq!(reader.fill_buf().unwrap(), b"age = 9\n");
+/// reader.consume(6);
+///
+/// let lines: Vec<String> = reader.lines().map(Result::unwrap).collect();
+/// assert_eq!(lines, ["9"]);
+/// ```
+#[cfg(feature = "std")]
+impl<U, T> std::io::BufRead for Usage<U, T>
+where
+    T: std::io::BufRead,
+{
+    fn fill_buf(&mut self) -> std::io::Result<&[u8]> {
+        self.data.fill_buf()
+    }
+
+    fn consume(&mut self, amt: usize) {
+        self.data.consume(amt)
+    }
+
+    fn read_until(&mut self, byte: u8, buf: &mut Vec<u8>) -> std::io::Result<usize> {
+        self.data.read_until(byte, buf)
+    }
+
+    fn read_line(&mut self, buf: &mut String) -> std::io::Result<usize> {
+        self.data.read_line(buf)
+    }
+}
+
+// Async traits
+/// Polls `T`, allowing tagged futures to be awaited:
+/// ```
+/// use std::future::Future;
+/// use std::pin::pin;
+/// use std::task::{Context, Poll, Waker};
+/// use usage::Usage;
+///
+/// enum DbQuery {}
+///
+/// async fn fetch_user() -> u64 {
+///     42
+/// }
+///
+/// let query = Usage::<DbQuery, _>::new(fetch_user());
+/// let task = pin!(async { query.await + 1 });
+///
+/// let mut cx = Context::from_waker(Waker::noop());
+/// assert_eq!(task.poll(&mut cx), Poll::Ready(43));
+/// ```
+impl<U, T> core::future::Future for Usage<U, T>
+where
+    T: core::future::Future,
+{
+    type Output = T::Output;
+
+    fn poll(
+        self: Pin<&mut Self>,
+        cx: &mut core::task::Context<'_>,
+    ) -> core::task::Poll<Self::Output> {
+        self.project_pin().poll(cx)
+    }
+}
+
+// Unwind safety
+/// [`UnwindSafe`] and [`RefUnwindSafe`] are predicated on `T` alone:
+/// ```
+/// use usage::Usage;
+///
+/// struct NotUnwindSafe(&'static mut ());
+///
+/// let usage = Usage::<NotUnwindSafe, String>::new("data".into());
+/// let result = std::panic::catch_unwind(move || usage.len());
+/// assert_eq!(result.unwrap(), 4);
+/// ```
+impl<U, T> UnwindSafe for Usage<U, T> where T: UnwindSafe {}
+
+impl<U, T> RefUnwindSafe for Usage<U, T> where T: RefUnwindSafe {}
+
+// Construction traits
+impl<U, T> From<T> for Usage<U, T> {
+    fn from(t: T) -> Self {
+        U::as_usage(t)
+    }
+}
+
+impl<U, T, V> FromIterator<V> for Usage<U, T>
+where
+    T: FromIterator<V>,
+{
+    fn from_iter<I: IntoIterator<Item = V>>(iter: I) -> Self {
+        U::as_usage(iter.into_iter().collect())
+    }
+}
+
+/// Parses `T` and wraps the result:
+/// ```
+/// use usage::Usage;
+///
+/// enum Port {}
+///
+/// let port = "8080".parse::<Usage<Port, u16>>().unwrap();
+/// assert_eq!(port.data, 8080);
+///
+/// let err: <u16 as std::str::FromStr>::Err = "http".parse::<Usage<Port, u16>>().unwrap_err();
+/// assert_eq!(err, "http".parse::<u16>().unwrap_err());
+/// ```
+impl<U, T> FromStr for Usage<U, T>
+where
+    T: FromStr,
+{
+    type Err = T::Err;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        T::from_str(s).map(U::as_usage)
+    }
+}
+
+#[cfg(feature = "rayon")]
+mod rayon_impl {
+    use super::*;
+    use rayon::iter::{
+        FromParallelIterator, IntoParallelIterator, ParallelExtend, ParallelIterator,
+    };
+    use rayon::slice::{ParallelSlice, ParallelSliceMut};
+
+    impl<U, T, V> FromParallelIterator<V> for Usage<U, T>
+    where
+        T: FromParallelIterator<V>,
+        V: Send,
+    {
+        fn from_par_iter<I: rayon::iter::IntoParallelIterator<Item = V>>(par_iter: I) -> Self {
+            U::as_usage(par_iter.into_par_iter().collect())
+        }
+    }
+
+    impl<U, T, V> ParallelExtend<V> for Usage<U, T>
+    where
+        T: ParallelExtend<V>,
+        V: Send,
+    {
+        fn par_extend<I: IntoParallelIterator<Item = V>>(&mut self, par_iter: I) {
+            self.data.par_extend(par_iter)
+        }
+    }
+
+    /// Forwards to the parallel iterators of `T`:
+    /// ```
+    /// use rayon::prelude::*;
+    /// use usage::Usage;
+    ///
+    /// enum Ids {}
+    ///
+    /// let mut ids = Usage::<Ids, Vec<usize>>::new((1..=100).collect());
+    ///
+    /// assert_eq!(ids.par_iter().sum::<usize>(), 5050);
+    ///
+    /// ids.par_iter_mut().for_each(|id| *id *= 2);
+    /// assert_eq!((&ids).into_par_iter().sum::<usize>(), 10100);
+    ///
+    /// assert_eq!(ids.into_par_iter().max(), Some(200));
+    /// ```
+    impl<U, T> IntoParallelIterator for Usage<U, T>
+    where
+        T: IntoParallelIterator,
+    {
+        type Iter = T::Iter;
+        type Item = T::Item;
+
+        fn into_par_iter(self) -> Self::Iter {
+            self.data.into_par_iter()
+        }
+    }
+
+    impl<'a, U, T> IntoParallelIterator for &'a Usage<U, T>
+    where
+        &'a T: IntoParallelIterator,
+    {
+        type Iter = <&'a T as IntoParallelIterator>::Iter;
+        type Item = <&'a T as IntoParallelIterator>::Item;
+
+        fn into_par_iter(self) -> Self::Iter {
+            (&self.data).into_par_iter()
+        }
+    }
+
+    impl<'a, U, T> IntoParallelIterator for &'a mut Usage<U, T>
+    where
+        &'a mut T: IntoParallelIterator,
+    {
+        type Iter = <&'a mut T as IntoParallelIterator>::Iter;
+        type Item = <&'a mut T as IntoParallelIterator>::Item;
+
+        fn into_par_iter(self) -> Self::Iter {
+            (&mut self.data).into_par_iter()
+        }
+    }
+
+    /// Exposes the tagged slice to rayon's `par_chunks`, `par_windows` and friends,
+    /// whose iterators are indexed:
+    /// ```
+    /// use rayon::prelude::*;
+    /// use usage::Usage;
+    ///
+    /// enum Samples {}
+    ///
+    /// let samples = Usage::<Samples, Vec<u32>>::new(vec![1, 2, 3, 4, 5]);
+    ///
+    /// let sums: Vec<u32> = samples.par_chunks(2).map(|c| c.iter().sum()).collect();
+    /// assert_eq!(sums, [3, 7, 5]);
+    ///
+    /// let windows: Vec<u32> = samples.par_windows(2).map(|w| w[1] - w[0]).collect();
+    /// assert_eq!(windows, [1, 1, 1, 1]);
+    ///
+    /// let (index, _) = samples
+    ///     .par_chunks(2)
+    ///     .enumerate()
+    ///     .find_any(|(_, c)| c.len() == 1)
+    ///     .unwrap();
+    /// assert_eq!(index, 2);
+    /// ```
+    impl<U, T, E> ParallelSlice<E> for Usage<U, T>
+    where
+        T: AsRef<[E]>,
+        E: Sync,
+    {
+        fn as_parallel_slice(&self) -> &[E] {
+            self.data.as_ref()
+        }
+    }
+
+    /// ```
+    /// use rayon::prelude::*;
+    /// use usage::Usage;
+    ///
+    /// enum Samples {}
+    ///
+    /// let mut samples = Usage::<Samples, Vec<u32>>::new(vec![4, 1, 3, 2]);
+    ///
+    /// samples.par_chunks_mut(2).for_each(|c| c.reverse());
+    /// assert_eq!(samples.data, [1, 4, 2, 3]);
+    ///
+    /// samples.par_sort();
+    /// assert_eq!(samples.data, [1, 2, 3, 4]);
+    /// ```
+    impl<U, T, E> ParallelSliceMut<E> for Usage<U, T>
+    where
+        T: AsMut<[E]>,
+        E: Send,
+    {
+        fn as_parallel_slice_mut(&mut self) -> &mut [E] {
+            self.data.as_mut()
+        }
+    }
+}
+
+#[cfg(feature = "bytemuck")]
+mod bytemuck_impl {
+    use super::*;
+    use bytemuck::{Contiguous, Pod, TransparentWrapper, Zeroable};
+
+    unsafe impl<U, T> Zeroable for Usage<U, T> where T: Zeroable {}
+
+    /// `Usage` is `repr(transparent)`, so it is `Pod` whenever `T` is.
+    ///
+    /// `NoUninit` and `CheckedBitPattern` come along for free via bytemuck's
+    /// blanket impls over `Pod`; implementing them directly would overlap.
+    /// ```
+    /// use usage::Usage;
+    ///
+    /// enum Tag {}
+    ///
+    /// let values = [Usage::<Tag, u32>::new(1), Usage::new(0x0403_0201)];
+    /// let bytes: &[u8] = bytemuck::cast_slice(&values);
+    ///
+    /// assert_eq!(bytes.len(), 8);
+    /// assert_eq!(&bytes[4..], &0x0403_0201u32.to_ne_bytes());
+    ///
+    /// let bits: &[u8] = bytemuck::checked::cast_slice(&values);
+    /// assert_eq!(bits, bytes);
+    ///
+    /// let back: &[Usage<Tag, u32>] = bytemuck::checked::try_cast_slice(bytes).unwrap();
+    /// assert_eq!(back, &values);
+    ///
+    /// fn assert_no_uninit<X: bytemuck::NoUninit>() {}
+    /// fn assert_checked<X: bytemuck::CheckedBitPattern>() {}
+    /// assert_no_uninit::<Usage<Tag, u32>>();
+    /// assert_checked::<Usage<Tag, u32>>();
+    /// ```
+    unsafe impl<U, T> Pod for Usage<U, T>
+    where
+        U: 'static,
+        T: Pod,
+    {
+    }
+
+    /// Sound because `Usage` is `repr(transparent)` over `T`,
+    /// and its only other field is a zero-sized `PhantomData`:
+    /// ```
+    /// use bytemuck::TransparentWrapper;
+    /// use usage::Usage;
+    ///
+    /// enum Meters {}
+    ///
+    /// let value = 5.0f32;
+    /// let meters: &Usage<Meters, f32> = TransparentWrapper::wrap_ref(&value);
+    /// assert_eq!(meters.data, 5.0);
+    ///
+    /// let inner: &f32 = TransparentWrapper::peel_ref(meters);
+    /// assert!(std::ptr::eq(inner, &value));
+    ///
+    /// let values = [1.0f32, 2.0, 3.0];
+    /// let tagged: &[Usage<Meters, f32>] = TransparentWrapper::wrap_slice(&values);
+    /// assert_eq!(tagged[2].data, 3.0);
+    /// ```
+    unsafe impl<U, T> TransparentWrapper<T> for Usage<U, T> {}
+
+    /// Forwards the integer range of `T`:
+    /// ```
+    /// use bytemuck::Contiguous;
+    /// use usage::Usage;
+    ///
+    /// #[repr(u8)]
+    /// #[derive(Debug, Copy, Clone, PartialEq)]
+    /// enum Level {
+    ///     Low = 1,
+    ///     Mid = 2,
+    ///     High = 3,
+    /// }
+    ///
+    /// unsafe impl Contiguous for Level {
+    ///     type Int = u8;
+    ///     const MIN_VALUE: u8 = Level::Low as u8;
+    ///     const MAX_VALUE: u8 = Level::High as u8;
+    /// }
+    ///
+    /// enum Volume {}
+    ///
+    /// type VolumeLevel = Usage<Volume, Level>;
+    ///
+    /// assert_eq!(VolumeLevel::MIN_VALUE, 1);
+    /// assert_eq!(VolumeLevel::MAX_VALUE, 3);
+    ///
+    /// let mid = VolumeLevel::from_integer(2).unwrap();
+    /// assert_eq!(mid.data, Level::Mid);
+    /// assert_eq!(mid.into_integer(), 2);
+    ///
+    /// assert!(VolumeLevel::from_integer(0).is_none());
+    /// assert!(VolumeLevel::from_integer(4).is_none());
+    /// ```
+    unsafe impl<U, T> Contiguous for Usage<U, T>
+    where
+        U: 'static,
+        T: Contiguous,
+    {
+        type Int = T::Int;
+
+        const MAX_VALUE: Self::Int = T::MAX_VALUE;
+        const MIN_VALUE: Self::Int = T::MIN_VALUE;
+    }
+}
+
+#[cfg(feature = "serde")]
+mod serde_impl {
+    use super::*;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    /// Serializes transparently as `T`:
+    /// ```
+    /// use usage::Usage;
+    ///
+    /// enum Id {}
+    ///
+    /// let id = Usage::<Id, u64>::new(42);
+    /// assert_eq!(serde_json::to_string(&id).unwrap(), serde_json::to_string(&42_u64).unwrap());
+    ///
+    /// let ids = Usage::<Id, Vec<u64>>::new(vec![1, 2]);
+    /// assert_eq!(serde_json::to_value(&ids).unwrap(), serde_json::json!([1, 2]));
+    /// ```
+    impl<U, T> Serialize for Usage<U, T>
+    where
+        T: Serialize,
+    {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            self.data.serialize(serializer)
+        }
+    }
+
+    /// Deserializes transparently from the representation of `T`:
+    /// ```
+    /// use usage::Usage;
+    ///
+    /// enum Id {}
+    ///
+    /// let id: Usage<Id, u64> = serde_json::from_str("42").unwrap();
+    /// assert_eq!(id.data, 42);
+    ///
+    /// let round_trip: Usage<Id, u64> = serde_json::from_str(&serde_json::to_string(&id).unwrap()).unwrap();
+    /// assert_eq!(round_trip, id);
+    ///
+    /// assert!(serde_json::from_str::<Usage<Id, u64>>("\"42\"").is_err());
+    /// assert!(serde_json::from_str::<Usage<Id, u64>>("-1").is_err());
+    /// assert!(serde_json::from_str::<Usage<Id, u64>>(r#"{"data": 42}"#).is_err());
+    /// ```
+    impl<'de, U, T> Deserialize<'de> for Usage<U, T>
+    where
+        T: Deserialize<'de>,
+    {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            T::deserialize(deserializer).map(U::as_usage)
+        }
+    }
+}
+
+#[cfg(feature = "serde_with")]
+mod serde_with_impl {
+    use super::*;
+    use serde::{Deserializer, Serializer};
+    use serde_with::{DeserializeAs, SerializeAs};
+
+    /// Allows `Usage<U, TAs>` to be used as a `serde_with` adapter for `Usage<U, T>`,
+    /// delegating to the inner adapter `TAs`:
+    /// ```
+    /// use serde::{Deserialize, Serialize};
+    /// use serde_with::{serde_as, DisplayFromStr};
+    /// use usage::Usage;
+    ///
+    /// enum Id {}
+    ///
+    /// #[serde_as]
+    /// #[derive(Serialize, Deserialize)]
+    /// struct User {
+    ///     #[serde_as(as = "Usage<Id, DisplayFromStr>")]
+    ///     id: Usage<Id, u64>,
+    ///     #[serde_as(as = "Vec<Usage<Id, DisplayFromStr>>")]
+    ///     friends: Vec<Usage<Id, u64>>,
+    /// }
+    ///
+    /// let user = User {
+    ///     id: Usage::new(42),
+    ///     friends: vec![Usage::new(1), Usage::new(2)],
+    /// };
+    ///
+    /// let json = serde_json::to_string(&user).unwrap();
+    /// assert_eq!(json, r#"{"id":"42","friends":["1","2"]}"#);
+    ///
+    /// let user: User = serde_json::from_str(&json).unwrap();
+    /// assert_eq!(user.id.data, 42);
+    /// assert_eq!(user.friends, [1, 2].map(Usage::<Id, u64>::new));
+    /// ```
+    impl<U, T, TAs> SerializeAs<Usage<U, T>> for Usage<U, TAs>
+    where
+        TAs: SerializeAs<T>,
+    {
+        fn serialize_as<S: Serializer>(
+            source: &Usage<U, T>,
+            serializer: S,
+        ) -> Result<S::Ok, S::Error> {
+            TAs::serialize_as(&source.data, serializer)
+        }
+    }
+
+    impl<'de, U, T, TAs> DeserializeAs<'de, Usage<U, T>> for Usage<U, TAs>
+    where
+        TAs: DeserializeAs<'de, T>,
+    {
+        fn deserialize_as<D: Deserializer<'de>>(deserializer: D) -> Result<Usage<U, T>, D::Error> {
+            TAs::deserialize_as(deserializer).map(U::as_usage)
+        }
+    }
+}
+
+#[cfg(feature = "num-traits")]
+mod num_traits_impl {
+    use super::*;
+    use num_traits::{
+        Bounded, CheckedAdd, CheckedDiv, CheckedMul, CheckedSub, Euclid, Float, FromPrimitive,
+        MulAdd, MulAddAssign, Num, NumCast, One, SaturatingAdd, SaturatingMul, SaturatingSub,
+        Signed, ToPrimitive, Unsigned, WrappingAdd, WrappingMul, WrappingSub, Zero,
+    };
+
+    /// Numeric identities carry the tag:
+    /// ```
+    /// use num_traits::{One, Zero};
+    /// use usage::Usage;
+    ///
+    /// enum Money {}
+    ///
+    /// let zero = Usage::<Money, i32>::zero();
+    /// assert!(zero.is_zero());
+    /// assert_eq!(zero.data, 0);
+    ///
+    /// let one = Usage::<Money, i32>::one();
+    /// assert!(one.is_one());
+    /// assert!(!one.is_zero());
+    /// assert!(!zero.is_one());
+    ///
+    /// let mut value = Usage::<Money, i64>::new(5);
+    /// value.set_zero();
+    /// assert_eq!(value.data, 0);
+    /// value.set_one();
+    /// assert_eq!(value.data, 1);
+    /// ```
+    impl<U, T> Zero for Usage<U, T>
+    where
+        T: Zero,
+    {
+        fn zero() -> Self {
+            U::as_usage(T::zero())
+        }
+
+        fn set_zero(&mut self) {
+            self.data.set_zero()
+        }
+
+        fn is_zero(&self) -> bool {
+            self.data.is_zero()
+        }
+    }
+
+    impl<U, T> One for Usage<U, T>
+    where
+        T: One + PartialEq,
+    {
+        fn one() -> Self {
+            U::as_usage(T::one())
+        }
+
+        fn set_one(&mut self) {
+            self.data.set_one()
+        }
+
+        fn is_one(&self) -> bool
+        where
+            Self: PartialEq,
+        {
+            self.data.is_one()
+        }
+    }
+
+    /// Allows `Usage<U, T>` to be used as a generic numeric type,
+    /// with [`NumOps`](num_traits::NumOps) provided by its tag-preserving operator impls:
+    /// ```
+    /// use num_traits::{Num, NumAssign, NumRef};
+    /// use usage::Usage;
+    ///
+    /// enum Tag {}
+    ///
+    /// fn sum_all<N: Num + Copy>(values: &[N]) -> N {
+    ///     values.iter().fold(N::zero(), |acc, value| acc + *value)
+    /// }
+    ///
+    /// fn assert_num<N: Num + NumAssign + NumRef>() {}
+    /// assert_num::<Usage<Tag, i32>>();
+    ///
+    /// let values = [1, 2, 3].map(Usage::<Tag, i32>::new);
+    /// let sum: Usage<Tag, i32> = sum_all(&values);
+    /// assert_eq!(sum.data, 6);
+    ///
+    /// let parsed = Usage::<Tag, i32>::from_str_radix("ff", 16).unwrap();
+    /// assert_eq!(parsed.data, 255);
+    /// assert!(Usage::<Tag, i32>::from_str_radix("zz", 16).is_err());
+    /// ```
+    impl<U, T> Num for Usage<U, T>
+    where
+        T: Num,
+    {
+        type FromStrRadixErr = T::FromStrRadixErr;
+
+        fn from_str_radix(str: &str, radix: u32) -> Result<Self, Self::FromStrRadixErr> {
+            T::from_str_radix(str, radix).map(U::as_usage)
+        }
+    }
+
+    /// Bounds carry the tag:
+    /// ```
+    /// use num_traits::Bounded;
+    /// use usage::Usage;
+    ///
+    /// enum Reg {}
+    ///
+    /// assert_eq!(Usage::<Reg, u8>::min_value().data, u8::MIN);
+    /// assert_eq!(Usage::<Reg, u8>::max_value().data, u8::MAX);
+    /// assert_eq!(Usage::<Reg, i16>::min_value().data, i16::MIN);
+    /// ```
+    impl<U, T> Bounded for Usage<U, T>
+    where
+        T: Bounded,
+    {
+        fn min_value() -> Self {
+            U::as_usage(T::min_value())
+        }
+
+        fn max_value() -> Self {
+            U::as_usage(T::max_value())
+        }
+    }
+
+    /// Primitive conversions forward to `T`, and carry the tag when converting into a `Usage`:
+    /// ```
+    /// use num_traits::{FromPrimitive, NumCast, ToPrimitive};
+    /// use usage::Usage;
+    ///
+    /// enum Tag {}
+    ///
+    /// let value = Usage::<Tag, f64>::new(2.7);
+    /// assert_eq!(value.to_i64(), Some(2));
+    /// assert_eq!(value.to_u8(), Some(2));
+    /// assert_eq!(value.to_f32(), Some(2.7));
+    ///
+    /// let cast: Usage<Tag, i32> = NumCast::from(value).unwrap();
+    /// assert_eq!(cast.data, 2);
+    /// assert!(<Usage<Tag, u8> as NumCast>::from(Usage::<Tag, f64>::new(-1.0)).is_none());
+    ///
+    /// let from: Usage<Tag, u16> = FromPrimitive::from_i64(300).unwrap();
+    /// assert_eq!(from.data, 300);
+    /// assert!(Usage::<Tag, u8>::from_i64(300).is_none());
+    /// ```
+    impl<U, T> ToPrimitive for Usage<U, T>
+    where
+        T: ToPrimitive,
+    {
+        fn to_isize(&self) -> Option<isize> {
+            self.data.to_isize()
+        }
+
+        fn to_i8(&self) -> Option<i8> {
+            self.data.to_i8()
+        }
+
+        fn to_i16(&self) -> Option<i16> {
+            self.data.to_i16()
+        }
+
+        fn to_i32(&self) -> Option<i32> {
+            self.data.to_i32()
+        }
+
+        fn to_i64(&self) -> Option<i64> {
+            self.data.to_i64()
+        }
+
+        fn to_i128(&self) -> Option<i128> {
+            self.data.to_i128()
+        }
+
+        fn to_usize(&self) -> Option<usize> {
+            self.data.to_usize()
+        }
+
+        fn to_u8(&self) -> Option<u8> {
+            self.data.to_u8()
+        }
+
+        fn to_u16(&self) -> Option<u16> {
+            self.data.to_u16()
+        }
+
+        fn to_u32(&self) -> Option<u32> {
+            self.data.to_u32()
+        }
+
+        fn to_u64(&self) -> Option<u64> {
+            self.data.to_u64()
+        }
+
+        fn to_u128(&self) -> Option<u128> {
+            self.data.to_u128()
+        }
+
+        fn to_f32(&self) -> Option<f32> {
+            self.data.to_f32()
+        }
+
+        fn to_f64(&self) -> Option<f64> {
+            self.data.to_f64()
+        }
+    }
+
+    impl<U, T> FromPrimitive for Usage<U, T>
+    where
+        T: FromPrimitive,
+    {
+        fn from_isize(n: isize) -> Option<Self> {
+            T::from_isize(n).map(U::as_usage)
+        }
+
+        fn from_i8(n: i8) -> Option<Self> {
+            T::from_i8(n).map(U::as_usage)
+        }
+
+        fn from_i16(n: i16) -> Option<Self> {
+            T::from_i16(n).map(U::as_usage)
+        }
+
+        fn from_i32(n: i32) -> Option<Self> {
+            T::from_i32(n).map(U::as_usage)
+        }
+
+        fn from_i64(n: i64) -> Option<Self> {
+            T::from_i64(n).map(U::as_usage)
+        }
+
+        fn from_i128(n: i128) -> Option<Self> {
+            T::from_i128(n).map(U::as_usage)
+        }
+
+        fn from_usize(n: usize) -> Option<Self> {
+            T::from_usize(n).map(U::as_usage)
+        }
+
+        fn from_u8(n: u8) -> Option<Self> {
+            T::from_u8(n).map(U::as_usage)
+        }
+
+        fn from_u16(n: u16) -> Option<Self> {
+            T::from_u16(n).map(U::as_usage)
+        }
+
+        fn from_u32(n: u32) -> Option<Self> {
+            T::from_u32(n).map(U::as_usage)
+        }
+
+        fn from_u64(n: u64) -> Option<Self> {
+            T::from_u64(n).map(U::as_usage)
+        }
+
+        fn from_u128(n: u128) -> Option<Self> {
+            T::from_u128(n).map(U::as_usage)
+        }
+
+        fn from_f32(n: f32) -> Option<Self> {
+            T::from_f32(n).map(U::as_usage)
+        }
+
+        fn from_f64(n: f64) -> Option<Self> {
+            T::from_f64(n).map(U::as_usage)
+        }
+    }
+
+    impl<U, T> NumCast for Usage<U, T>
+    where
+        T: NumCast,
+    {
+        fn from<N: ToPrimitive>(n: N) -> Option<Self> {
+            <T as NumCast>::from(n).map(U::as_usage)
+        }
+    }
+
+    /// Sign-related operations preserve the tag:
+    /// ```
+    /// use num_traits::{Signed, Unsigned};
+    /// use usage::Usage;
+    ///
+    /// enum Tag {}
+    ///
+    /// let value = Usage::<Tag, i32>::new(-4);
+    /// let abs: Usage<Tag, i32> = Signed::abs(&value);
+    /// assert_eq!(abs.data, 4);
+    /// assert_eq!(value.signum().data, -1);
+    /// assert_eq!(Signed::abs_sub(&value, &Usage::new(-6)).data, 2);
+    /// assert!(value.is_negative());
+    /// assert!(!value.is_positive());
+    ///
+    /// fn assert_unsigned<T: Unsigned>() {}
+    /// assert_unsigned::<Usage<Tag, u32>>();
+    /// ```
+    impl<U, T> Signed for Usage<U, T>
+    where
+        T: Signed,
+    {
+        fn abs(&self) -> Self {
+            U::as_usage(self.data.abs())
+        }
+
+        fn abs_sub(&self, other: &Self) -> Self {
+            U::as_usage(self.data.abs_sub(&other.data))
+        }
+
+        fn signum(&self) -> Self {
+            U::as_usage(self.data.signum())
+        }
+
+        fn is_positive(&self) -> bool {
+            self.data.is_positive()
+        }
+
+        fn is_negative(&self) -> bool {
+            self.data.is_negative()
+        }
+    }
+
+    impl<U, T> Unsigned for Usage<U, T> where T: Unsigned {}
+
+    /// Floating-point operations preserve the tag:
+    /// ```
+    /// use num_traits::Float;
+    /// use usage::Usage;
+    ///
+    /// enum Meters {}
+    ///
+    /// fn hypotenuse<F: Float>(a: F, b: F) -> F {
+    ///     (a * a + b * b).sqrt()
+    /// }
+    ///
+    /// let a = Usage::<Meters, f64>::new(3.0);
+    /// let b = Usage::<Meters, f64>::new(4.0);
+    /// let c: Usage<Meters, f64> = hypotenuse(a, b);
+    /// assert_eq!(c.data, 5.0);
+    ///
+    /// assert_eq!(Float::sqrt(Usage::<Meters, f64>::new(16.0)).data, 4.0);
+    /// assert_eq!(Float::abs(Usage::<Meters, f64>::new(-1.5)).data, 1.5);
+    /// assert_eq!(Float::floor(Usage::<Meters, f64>::new(1.5)).data, 1.0);
+    /// assert_eq!(Float::sin(Usage::<Meters, f64>::new(0.5)).data, 0.5_f64.sin());
+    /// assert!(Float::is_nan(Usage::<Meters, f64>::nan()));
+    /// ```
+    impl<U, T> Float for Usage<U, T>
+    where
+        T: Float,
+    {
+        fn nan() -> Self {
+            U::as_usage(T::nan())
+        }
+
+        fn infinity() -> Self {
+            U::as_usage(T::infinity())
+        }
+
+        fn neg_infinity() -> Self {
+            U::as_usage(T::neg_infinity())
+        }
+
+        fn neg_zero() -> Self {
+            U::as_usage(T::neg_zero())
+        }
+
+        fn min_value() -> Self {
+            U::as_usage(T::min_value())
+        }
+
+        fn min_positive_value() -> Self {
+            U::as_usage(T::min_positive_value())
+        }
+
+        fn epsilon() -> Self {
+            U::as_usage(T::epsilon())
+        }
+
+        fn max_value() -> Self {
+            U::as_usage(T::max_value())
+        }
+
+        fn is_nan(self) -> bool {
+            self.data.is_nan()
+        }
+
+        fn is_infinite(self) -> bool {
+            self.data.is_infinite()
+        }
+
+        fn is_finite(self) -> bool {
+            self.data.is_finite()
+        }
+
+        fn is_normal(self) -> bool {
+            self.data.is_normal()
+        }
+
+        fn is_subnormal(self) -> bool {
+            self.data.is_subnormal()
+        }
+
+        fn classify(self) -> core::num::FpCategory {
+            self.data.classify()
+        }
+
+        fn floor(self) -> Self {
+            U::as_usage(self.data.floor())
+        }
+
+        fn ceil(self) -> Self {
+            U::as_usage(self.data.ceil())
+        }
+
+        fn round(self) -> Self {
+            U::as_usage(self.data.round())
+        }
+
+        fn trunc(self) -> Self {
+            U::as_usage(self.data.trunc())
+        }
+
+        fn fract(self) -> Self {
+            U::as_usage(self.data.fract())
+        }
+
+        fn abs(self) -> Self {
+            U::as_usage(self.data.abs())
+        }
+
+        fn signum(self) -> Self {
+            U::as_usage(self.data.signum())
+        }
+
+        fn is_sign_positive(self) -> bool {
+            self.data.is_sign_positive()
+        }
+
+        fn is_sign_negative(self) -> bool {
+            self.data.is_sign_negative()
+        }
+
+        fn mul_add(self, a: Self, b: Self) -> Self {
+            U::as_usage(self.data.mul_add(a.data, b.data))
+        }
+
+        fn recip(self) -> Self {
+            U::as_usage(self.data.recip())
+        }
+
+        fn powi(self, n: i32) -> Self {
+            U::as_usage(self.data.powi(n))
+        }
+
+        fn powf(self, n: Self) -> Self {
+            U::as_usage(self.data.powf(n.data))
+        }
+
+        fn sqrt(self) -> Self {
+            U::as_usage(self.data.sqrt())
+        }
+
+        fn exp(self) -> Self {
+            U::as_usage(self.data.exp())
+        }
+
+        fn exp2(self) -> Self {
+            U::as_usage(self.data.exp2())
+        }
+
+        fn ln(self) -> Self {
+            U::as_usage(self.data.ln())
+        }
+
+        fn log(self, base: Self) -> Self {
+            U::as_usage(self.data.log(base.data))
+        }
+
+        fn log2(self) -> Self {
+            U::as_usage(self.data.log2())
+        }
+
+        fn log10(self) -> Self {
+            U::as_usage(self.data.log10())
+        }
+
+        fn to_degrees(self) -> Self {
+            U::as_usage(self.data.to_degrees())
+        }
+
+        fn to_radians(self) -> Self {
+            U::as_usage(self.data.to_radians())
+        }
+
+        fn max(self, other: Self) -> Self {
+            U::as_usage(self.data.max(other.data))
+        }
+
+        fn min(self, other: Self) -> Self {
+            U::as_usage(self.data.min(other.data))
+        }
+
+        fn clamp(self, min: Self, max: Self) -> Self {
+            U::as_usage(self.data.clamp(min.data, max.data))
+        }
+
+        fn abs_sub(self, other: Self) -> Self {
+            U::as_usage(Float::abs_sub(self.data, other.data))
+        }
+
+        fn cbrt(self) -> Self {
+            U::as_usage(self.data.cbrt())
+        }
+
+        fn hypot(self, other: Self) -> Self {
+            U::as_usage(self.data.hypot(other.data))
+        }
+
+        fn sin(self) -> Self {
+            U::as_usage(self.data.sin())
+        }
+
+        fn cos(self) -> Self {
+            U::as_usage(self.data.cos())
+        }
+
+        fn tan(self) -> Self {
+            U::as_usage(self.data.tan())
+        }
+
+        fn asin(self) -> Self {
+            U::as_usage(self.data.asin())
+        }
+
+        fn acos(self) -> Self {
+            U::as_usage(self.data.acos())
+        }
+
+        fn atan(self) -> Self {
+            U::as_usage(self.data.atan())
+        }
+
+        fn atan2(self, other: Self) -> Self {
+            U::as_usage(self.data.atan2(other.data))
+        }
+
+        fn sin_cos(self) -> (Self, Self) {
+            let (sin, cos) = self.data.sin_cos();
+            (U::as_usage(sin), U::as_usage(cos))
+        }
+
+        fn exp_m1(self) -> Self {
+            U::as_usage(self.data.exp_m1())
+        }
+
+        fn ln_1p(self) -> Self {
+            U::as_usage(self.data.ln_1p())
+        }
+
+        fn sinh(self) -> Self {
+            U::as_usage(self.data.sinh())
+        }
+
+        fn cosh(self) -> Self {
+            U::as_usage(self.data.cosh())
+        }
+
+        fn tanh(self) -> Self {
+            U::as_usage(self.data.tanh())
+        }
+
+        fn asinh(self) -> Self {
+            U::as_usage(self.data.asinh())
+        }
+
+        fn acosh(self) -> Self {
+            U::as_usage(self.data.acosh())
+        }
+
+        fn atanh(self) -> Self {
+            U::as_usage(self.data.atanh())
+        }
+
+        fn integer_decode(self) -> (u64, i16, i8) {
+            self.data.integer_decode()
+        }
+
+        fn copysign(self, sign: Self) -> Self {
+            U::as_usage(self.data.copysign(sign.data))
+        }
+    }
+
+    macro_rules! impl_checked_ops {
+        ($($(#[$attr:meta])* $op:ident::$method:ident),* $(,)?) => {
+            $(
+                $(#[$attr])*
+                impl<U, T> $op for Usage<U, T>
+                where
+                    T: $op,
+                {
+                    fn $method(&self, v: &Self) -> Option<Self> {
+                        self.data.$method(&v.data).map(U::as_usage)
+                    }
+                }
+            )*
+        };
+    }
+
+    macro_rules! impl_overflowing_ops {
+        ($($(#[$attr:meta])* $op:ident::$method:ident),* $(,)?) => {
+            $(
+                $(#[$attr])*
+                impl<U, T> $op for Usage<U, T>
+                where
+                    T: $op,
+                {
+                    fn $method(&self, v: &Self) -> Self {
+                        U::as_usage(self.data.$method(&v.data))
+                    }
+                }
+            )*
+        };
+    }
+
+    impl_checked_ops!(
+        /// Checked operations preserve the tag:
+        /// ```
+        /// use num_traits::{CheckedAdd, CheckedDiv, CheckedMul, CheckedSub};
+        /// use usage::Usage;
+        ///
+        /// enum Tag {}
+        ///
+        /// let max = Usage::<Tag, u8>::new(u8::MAX);
+        /// let one = Usage::<Tag, u8>::new(1);
+        /// let zero = Usage::<Tag, u8>::new(0);
+        ///
+        /// assert_eq!(CheckedAdd::checked_add(&one, &one).unwrap().data, 2);
+        /// assert!(CheckedAdd::checked_add(&max, &one).is_none());
+        /// assert!(CheckedSub::checked_sub(&zero, &one).is_none());
+        /// assert!(CheckedMul::checked_mul(&max, &Usage::new(2)).is_none());
+        /// assert!(CheckedDiv::checked_div(&one, &zero).is_none());
+        /// ```
+        CheckedAdd::checked_add,
+        CheckedSub::checked_sub,
+        CheckedMul::checked_mul,
+        CheckedDiv::checked_div,
+    );
+
+    impl_overflowing_ops!(
+        /// Saturating and wrapping operations preserve the tag:
+        /// ```
+        /// use num_traits::{
+        ///     SaturatingAdd, SaturatingMul, SaturatingSub, WrappingAdd, WrappingMul, WrappingSub,
+        /// };
+        /// use usage::Usage;
+        ///
+        /// enum Tag {}
+        ///
+        /// let max = Usage::<Tag, u8>::new(u8::MAX);
+        /// let one = Usage::<Tag, u8>::new(1);
+        /// let zero = Usage::<Tag, u8>::new(0);
+        /// let two = Usage::<Tag, u8>::new(2);
+        ///
+        /// assert_eq!(SaturatingAdd::saturating_add(&max, &one).data, u8::MAX);
+        /// assert_eq!(SaturatingSub::saturating_sub(&zero, &one).data, 0);
+        /// assert_eq!(SaturatingMul::saturating_mul(&max, &two).data, u8::MAX);
+        ///
+        /// assert_eq!(WrappingAdd::wrapping_add(&max, &one).data, 0);
+        /// assert_eq!(WrappingSub::wrapping_sub(&zero, &one).data, u8::MAX);
+        /// assert_eq!(WrappingMul::wrapping_mul(&max, &two).data, 254);
+        /// ```
+        SaturatingAdd::saturating_add,
+        SaturatingSub::saturating_sub,
+        SaturatingMul::saturating_mul,
+        WrappingAdd::wrapping_add,
+        WrappingSub::wrapping_sub,
+        WrappingMul::wrapping_mul,
+    );
+
+    /// Fused multiply-add preserves the tag:
+    /// ```
+    /// use num_traits::{MulAdd, MulAddAssign};
+    /// use usage::Usage;
+    ///
+    /// enum Tag {}
+    ///
+    /// let x = Usage::<Tag, f64>::new(2.0);
+    /// let a = Usage::<Tag, f64>::new(3.0);
+    /// let b = Usage::<Tag, f64>::new(0.5);
+    ///
+    /// let result: Usage<Tag, f64> = MulAdd::mul_add(x, a, b);
+    /// assert_eq!(result.data, MulAdd::mul_add(2.0_f64, 3.0, 0.5));
+    ///
+    /// let mut x = x;
+    /// MulAddAssign::mul_add_assign(&mut x, a, b);
+    /// assert_eq!(x.data, 6.5);
+    ///
+    /// let i: Usage<Tag, i32> = MulAdd::mul_add(Usage::new(4), Usage::new(5), Usage::new(-1));
+    /// assert_eq!(i.data, 19);
+    /// ```
+    impl<U, T> MulAdd for Usage<U, T>
+    where
+        T: MulAdd,
+    {
+        type Output = Usage<U, T::Output>;
+
+        fn mul_add(self, a: Self, b: Self) -> Self::Output {
+            U::as_usage(self.data.mul_add(a.data, b.data))
+        }
+    }
+
+    impl<U, T> MulAddAssign for Usage<U, T>
+    where
+        T: MulAddAssign,
+    {
+        fn mul_add_assign(&mut self, a: Self, b: Self) {
+            self.data.mul_add_assign(a.data, b.data)
+        }
+    }
+
+    /// Euclidean division preserves the tag:
+    /// ```
+    /// use num_traits::Euclid;
+    /// use usage::Usage;
+    ///
+    /// enum Tag {}
+    ///
+    /// let a = Usage::<Tag, i32>::new(-7);
+    /// let b = Usage::<Tag, i32>::new(4);
+    ///
+    /// assert_eq!(Euclid::div_euclid(&a, &b).data, (-7_i32).div_euclid(4));
+    /// assert_eq!(Euclid::rem_euclid(&a, &b).data, (-7_i32).rem_euclid(4));
+    /// assert_eq!(Euclid::rem_euclid(&Usage::<Tag, f64>::new(-7.5), &Usage::new(2.0)).data, 0.5);
+    /// ```
+    impl<U, T> Euclid for Usage<U, T>
+    where
+        T: Euclid,
+    {
+        fn div_euclid(&self, v: &Self) -> Self {
+            U::as_usage(self.data.div_euclid(&v.data))
+        }
+
+        fn rem_euclid(&self, v: &Self) -> Self {
+            U::as_usage(self.data.rem_euclid(&v.data))
+        }
+    }
+}
+
+#[cfg(feature = "approx")]
+mod approx_impl {
+    use super::*;
+    use approx::{AbsDiffEq, RelativeEq, UlpsEq};
+
+    /// Approximate equality forwards to `T`:
+    /// ```
+    /// use approx::{assert_abs_diff_eq, assert_abs_diff_ne, AbsDiffEq};
+    /// use usage::Usage;
+    ///
+    /// enum Meters {}
+    ///
+    /// let a = Usage::<Meters, f64>::new(1.0);
+    /// let b = Usage::<Meters, f64>::new(1.0 + f64::EPSILON / 2.0);
+    /// assert_abs_diff_eq!(a, b);
+    /// assert_abs_diff_eq!(a, Usage::new(1.05), epsilon = 0.1);
+    /// assert_abs_diff_ne!(a, Usage::new(1.05));
+    /// assert_eq!(Usage::<Meters, f64>::default_epsilon(), f64::EPSILON);
+    /// ```
+    impl<U, T> AbsDiffEq for Usage<U, T>
+    where
+        T: AbsDiffEq,
+    {
+        type Epsilon = T::Epsilon;
+
+        fn default_epsilon() -> Self::Epsilon {
+            T::default_epsilon()
+        }
+
+        fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+            self.data.abs_diff_eq(&other.data, epsilon)
+        }
+    }
+
+    /// ```
+    /// use approx::{assert_relative_eq, assert_relative_ne, RelativeEq};
+    /// use usage::Usage;
+    ///
+    /// enum Meters {}
+    ///
+    /// let a = Usage::<Meters, f64>::new(1000.0);
+    /// assert_relative_eq!(a, Usage::new(1000.0 + 1e-13));
+    /// assert_relative_eq!(a, Usage::new(1001.0), max_relative = 0.01);
+    /// assert_relative_ne!(a, Usage::new(1001.0));
+    /// assert_eq!(Usage::<Meters, f64>::default_max_relative(), f64::default_max_relative());
+    /// ```
+    impl<U, T> RelativeEq for Usage<U, T>
+    where
+        T: RelativeEq,
+    {
+        fn default_max_relative() -> Self::Epsilon {
+            T::default_max_relative()
+        }
+
+        fn relative_eq(
+            &self,
+            other: &Self,
+            epsilon: Self::Epsilon,
+            max_relative: Self::Epsilon,
+        ) -> bool {
+            self.data.relative_eq(&other.data, epsilon, max_relative)
+        }
+    }
+
+    /// ```
+    /// use approx::{assert_ulps_eq, assert_ulps_ne, UlpsEq};
+    /// use usage::Usage;
+    ///
+    /// enum Meters {}
+    ///
+    /// let a = Usage::<Meters, f64>::new(1.0);
+    /// assert_ulps_eq!(a, Usage::new(1.0 + f64::EPSILON));
+    /// assert_ulps_ne!(a, Usage::new(1.0 + 1e-6));
+    /// assert_ulps_ne!(a, Usage::new(1.0 + f64::EPSILON * 3.0), max_ulps = 2);
+    /// assert_eq!(Usage::<Meters, f64>::default_max_ulps(), f64::default_max_ulps());
+    /// ```
+    impl<U, T> UlpsEq for Usage<U, T>
+    where
+        T: UlpsEq,
+    {
+        fn default_max_ulps() -> u32 {
+            T::default_max_ulps()
+        }
+
+        fn ulps_eq(&self, other: &Self, epsilon: Self::Epsilon, max_ulps: u32) -> bool {
+            self.data.ulps_eq(&other.data, epsilon, max_ulps)
+        }
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+mod arbitrary_impl {
+    use super::*;
+    use arbitrary::{Arbitrary, MaxRecursionReached, Result, Unstructured};
+
+    /// Generates `T` and wraps it, with the tag consuming no input:
+    /// ```
+    /// use arbitrary::{Arbitrary, Unstructured};
+    /// use usage::Usage;
+    ///
+    /// enum Tag {}
+    ///
+    /// let bytes = [1, 2, 3, 4, 5, 6, 7, 8];
+    ///
+    /// let usage = Usage::<Tag, u32>::arbitrary(&mut Unstructured::new(&bytes)).unwrap();
+    /// let inner = u32::arbitrary(&mut Unstructured::new(&bytes)).unwrap();
+    /// assert_eq!(usage.data, inner);
+    ///
+    /// let mut u = Unstructured::new(&bytes);
+    /// let _ = Usage::<Tag, u32>::arbitrary(&mut u).unwrap();
+    /// assert_eq!(u.len(), bytes.len() - 4);
+    ///
+    /// assert_eq!(Usage::<Tag, u32>::size_hint(0), u32::size_hint(0));
+    /// ```
+    impl<'a, U, T> Arbitrary<'a> for Usage<U, T>
+    where
+        T: Arbitrary<'a>,
+    {
+        fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+            T::arbitrary(u).map(U::as_usage)
+        }
+
+        fn arbitrary_take_rest(u: Unstructured<'a>) -> Result<Self> {
+            T::arbitrary_take_rest(u).map(U::as_usage)
+        }
+
+        fn size_hint(depth: usize) -> (usize, Option<usize>) {
+            T::size_hint(depth)
+        }
+
+        fn try_size_hint(
+            depth: usize,
+        ) -> core::result::Result<(usize, Option<usize>), MaxRecursionReached> {
+            T::try_size_hint(depth)
+        }
+    }
+}
+
+#[cfg(feature = "proptest")]
+mod proptest_impl {
+    use super::*;
+    use proptest::{
+        arbitrary::{any_with, Arbitrary},
+        strategy::{Map, Strategy},
+    };
+
+    /// Generates and shrinks via the strategy for `T`, wrapping each value:
+    /// ```
+    /// use proptest::{
+    ///     prelude::*,
+    ///     test_runner::{TestError, TestRunner},
+    /// };
+    /// use usage::Usage;
+    ///
+    /// enum Tag {}
+    ///
+    /// proptest!(|(usage in any::<Usage<Tag, Vec<u8>>>())| {
+    ///     prop_assert_eq!(usage.len(), usage.data.len());
+    /// });
+    ///
+    /// let result = TestRunner::default().run(&any::<Usage<Tag, Vec<u8>>>(), |usage| {
+    ///     prop_assert!(usage.len() < 3);
+    ///     Ok(())
+    /// });
+    ///
+    /// match result {
+    ///     Err(TestError::Fail(_, minimal)) => {
+    ///         let minimal: Usage<Tag, Vec<u8>> = minimal;
+    ///         assert_eq!(minimal.data, [0, 0, 0]);
+    ///     }
+    ///     result => panic!("Unexpected result: {:?}", result),
+    /// }
+    /// ```
+    impl<U, T> Arbitrary for Usage<U, T>
+    where
+        T: Arbitrary,
+    {
+        type Parameters = T::Parameters;
+        type Strategy = Map<T::Strategy, fn(T) -> Self>;
+
+        fn arbitrary_with(args: Self::Parameters) -> Self::Strategy {
+            any_with::<T>(args).prop_map(U::as_usage)
+        }
+    }
+}
+
+#[cfg(feature = "quickcheck")]
+mod quickcheck_impl {
+    use super::*;
+    use quickcheck::{Arbitrary, Gen};
+
+    /// Generates and shrinks via `T`, wrapping each value:
+    /// ```
+    /// use quickcheck::{quickcheck, Arbitrary};
+    /// use usage::Usage;
+    ///
+    /// enum Tag {}
+    ///
+    /// fn round_trip(usage: Usage<Tag, Vec<u32>>) -> bool {
+    ///     Usage::<Tag, Vec<u32>>::new(usage.data.clone()) == usage
+    /// }
+    ///
+    /// quickcheck(round_trip as fn(Usage<Tag, Vec<u32>>) -> bool);
+    ///
+    /// let shrunk: Vec<u32> = Usage::<Tag, u32>::new(10)
+    ///     .shrink()
+    ///     .map(|usage: Usage<Tag, u32>| usage.data)
+    ///     .collect();
+    /// assert_eq!(shrunk, 10.shrink().collect::<Vec<_>>());
+    /// ```
+    impl<U, T> Arbitrary for Usage<U, T>
+    where
+        U: 'static,
+        T: Arbitrary,
+    {
+        fn arbitrary(g: &mut Gen) -> Self {
+            U::as_usage(T::arbitrary(g))
+        }
+
+        fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+            Box::new(self.data.shrink().map(U::as_usage))
+        }
+    }
+}
+
+#[cfg(feature = "rand")]
+mod rand_impl {
+    use super::*;
+    use rand::{
+        distributions::{Distribution, Standard},
+        Rng,
+    };
+
+    /// Samples `T` and wraps it:
+    /// ```
+    /// use rand::{rngs::StdRng, Rng, SeedableRng};
+    /// use usage::Usage;
+    ///
+    /// enum Tag {}
+    ///
+    /// let mut rng = StdRng::seed_from_u64(42);
+    /// let tagged: Vec<Usage<Tag, u32>> = (0..4).map(|_| rng.gen()).collect();
+    ///
+    /// let mut rng = StdRng::seed_from_u64(42);
+    /// let raw: Vec<u32> = (0..4).map(|_| rng.gen()).collect();
+    ///
+    /// assert_eq!(tagged.iter().map(|usage| usage.data).collect::<Vec<_>>(), raw);
+    /// ```
+    impl<U, T> Distribution<Usage<U, T>> for Standard
+    where
+        Standard: Distribution<T>,
+    {
+        fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Usage<U, T> {
+            U::as_usage(self.sample(rng))
+        }
+    }
+
+    /// Adapter for producing [`Usage<U, T>`] samples from any [`Distribution<T>`]
+    /// ```
+    /// use rand::{distributions::Uniform, rngs::StdRng, Rng, SeedableRng};
+    /// use usage::{TaggedDist, Usage};
+    ///
+    /// enum Meters {}
+    ///
+    /// let dist = TaggedDist::<Meters, _>::new(Uniform::new(0.0, 10.0));
+    /// let mut rng = StdRng::seed_from_u64(42);
+    ///
+    /// for _ in 0..16 {
+    ///     let distance: Usage<Meters, f64> = rng.sample(&dist);
+    ///     assert!((0.0..10.0).contains(&distance.data));
+    /// }
+    ///
+    /// let tagged: Vec<Usage<Meters, f64>> = StdRng::seed_from_u64(1).sample_iter(&dist).take(4).collect();
+    /// let raw: Vec<f64> = StdRng::seed_from_u64(1).sample_iter(dist.into_inner()).take(4).collect();
+    /// assert_eq!(tagged.iter().map(|usage| usage.data).collect::<Vec<_>>(), raw);
+    /// ```
+    pub struct TaggedDist<U, D> {
+        dist: D,
+        _phantom: PhantomData<fn(U) -> U>,
+    }
+
+    impl<U, D> TaggedDist<U, D> {
+        /// Wrap `dist` so that its samples are tagged with `U`
+        pub const fn new(dist: D) -> Self {
+            TaggedDist {
+                dist,
+                _phantom: PhantomData,
+            }
+        }
+
+        /// Convert `TaggedDist<U, D>` into `D` by value
+        pub fn into_inner(self) -> D {
+            self.dist
+        }
+    }
+
+    impl<U, D> core::fmt::Debug for TaggedDist<U, D>
+    where
+        D: core::fmt::Debug,
+    {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            f.debug_struct("TaggedDist")
+                .field("dist", &self.dist)
+                .finish_non_exhaustive()
+        }
+    }
+
+    impl<U, D> Copy for TaggedDist<U, D> where D: Copy {}
+
+    impl<U, D> Clone for TaggedDist<U, D>
+    where
+        D: Clone,
+    {
+        fn clone(&self) -> Self {
+            TaggedDist::new(self.dist.clone())
+        }
+    }
+
+    impl<U, T, D> Distribution<Usage<U, T>> for TaggedDist<U, D>
+    where
+        D: Distribution<T>,
+    {
+        fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Usage<U, T> {
+            U::as_usage(self.dist.sample(rng))
+        }
+    }
+}
+
+#[cfg(feature = "rand")]
+pub use rand_impl::TaggedDist;
+
+#[cfg(feature = "sqlx")]
+mod sqlx_impl {
+    use super::*;
+    use sqlx::{encode::IsNull, error::BoxDynError, Database, Decode, Encode, Type};
+
+    /// Maps to the database type of `T`, so tagged values can be bound and decoded as `T`:
+    /// ```
+    /// use sqlx::{Decode, Encode, Postgres, Type};
+    /// use usage::Usage;
+    ///
+    /// enum UserId {}
+    ///
+    /// fn assert_column<'r, T: Type<Postgres> + Encode<'r, Postgres> + Decode<'r, Postgres>>() {}
+    /// assert_column::<Usage<UserId, i64>>();
+    /// assert_column::<Usage<UserId, String>>();
+    ///
+    /// assert_eq!(
+    ///     <Usage<UserId, i64> as Type<Postgres>>::type_info(),
+    ///     <i64 as Type<Postgres>>::type_info(),
+    /// );
+    ///
+    /// let _query = sqlx::query("SELECT * FROM users WHERE id = $1")
+    ///     .bind(Usage::<UserId, i64>::new(42));
+    /// ```
+    impl<U, T, DB> Type<DB> for Usage<U, T>
+    where
+        T: Type<DB>,
+        DB: Database,
+    {
+        fn type_info() -> DB::TypeInfo {
+            T::type_info()
+        }
+
+        fn compatible(ty: &DB::TypeInfo) -> bool {
+            T::compatible(ty)
+        }
+    }
+
+    impl<'q, U, T, DB> Encode<'q, DB> for Usage<U, T>
+    where
+        T: Encode<'q, DB>,
+        DB: Database,
+    {
+        fn encode(self, buf: &mut DB::ArgumentBuffer<'q>) -> Result<IsNull, BoxDynError> {
+            self.data.encode(buf)
+        }
+
+        fn encode_by_ref(&self, buf: &mut DB::ArgumentBuffer<'q>) -> Result<IsNull, BoxDynError> {
+            self.data.encode_by_ref(buf)
+        }
+
+        fn produces(&self) -> Option<DB::TypeInfo> {
+            self.data.produces()
+        }
+
+        fn size_hint(&self) -> usize {
+            self.data.size_hint()
+        }
+    }
+
+    impl<'r, U, T, DB> Decode<'r, DB> for Usage<U, T>
+    where
+        T: Decode<'r, DB>,
+        DB: Database,
+    {
+        fn decode(value: DB::ValueRef<'r>) -> Result<Self, BoxDynError> {
+            T::decode(value).map(U::as_usage)
+        }
+    }
+}
+
+#[cfg(feature = "postgres-types")]
+mod postgres_types_impl {
+    use super::*;
+    use bytes::BytesMut;
+    use postgres_types::{FromSql, IsNull, ToSql, Type};
+    use std::error::Error;
+
+    /// Binds as a parameter of the same Postgres types as `T`:
+    /// ```
+    /// use bytes::BytesMut;
+    /// use postgres_types::{FromSql, ToSql, Type};
+    /// use usage::Usage;
+    ///
+    /// enum UserId {}
+    ///
+    /// assert!(<Usage<UserId, i64> as ToSql>::accepts(&Type::INT8));
+    /// assert!(!<Usage<UserId, i64> as ToSql>::accepts(&Type::TEXT));
+    ///
+    /// let mut buf = BytesMut::new();
+    /// Usage::<UserId, i64>::new(42).to_sql_checked(&Type::INT8, &mut buf).unwrap();
+    /// assert_eq!(&buf[..], &42i64.to_be_bytes());
+    ///
+    /// assert!(Usage::<UserId, i64>::new(42).to_sql_checked(&Type::TEXT, &mut buf).is_err());
+    /// ```
+    impl<U, T> ToSql for Usage<U, T>
+    where
+        T: ToSql,
+    {
+        fn to_sql(
+            &self,
+            ty: &Type,
+            out: &mut BytesMut,
+        ) -> Result<IsNull, Box<dyn Error + Sync + Send>> {
+            self.data.to_sql(ty, out)
+        }
+
+        fn accepts(ty: &Type) -> bool {
+            T::accepts(ty)
+        }
+
+        postgres_types::to_sql_checked!();
+
+        fn encode_format(&self, ty: &Type) -> postgres_types::Format {
+            self.data.encode_format(ty)
+        }
+    }
+
+    /// Reads from the same Postgres types as `T`:
+    /// ```
+    /// use postgres_types::{FromSql, Type};
+    /// use usage::Usage;
+    ///
+    /// enum UserId {}
+    ///
+    /// assert!(<Usage<UserId, i64> as FromSql>::accepts(&Type::INT8));
+    ///
+    /// let id = Usage::<UserId, i64>::from_sql(&Type::INT8, &42i64.to_be_bytes()).unwrap();
+    /// assert_eq!(id.data, 42);
+    ///
+    /// let id = Usage::<UserId, Option<i64>>::from_sql_null(&Type::INT8).unwrap();
+    /// assert_eq!(id.data, None);
+    /// ```
+    impl<'a, U, T> FromSql<'a> for Usage<U, T>
+    where
+        T: FromSql<'a>,
+    {
+        fn from_sql(ty: &Type, raw: &'a [u8]) -> Result<Self, Box<dyn Error + Sync + Send>> {
+            T::from_sql(ty, raw).map(U::as_usage)
+        }
+
+        fn from_sql_null(ty: &Type) -> Result<Self, Box<dyn Error + Sync + Send>> {
+            T::from_sql_null(ty).map(U::as_usage)
+        }
+
+        fn from_sql_nullable(
+            ty: &Type,
+            raw: Option<&'a [u8]>,
+        ) -> Result<Self, Box<dyn Error + Sync + Send>> {
+            T::from_sql_nullable(ty, raw).map(U::as_usage)
+        }
+
+        fn accepts(ty: &Type) -> bool {
+            T::accepts(ty)
+        }
+    }
+}
+
+#[cfg(feature = "rusqlite")]
+mod rusqlite_impl {
+    use super::*;
+    use rusqlite::types::{FromSql, FromSqlResult, ToSql, ToSqlOutput, ValueRef};
+
+    /// Reads and writes SQLite values as `T`.
+    impl<U, T> ToSql for Usage<U, T>
+    where
+        T: ToSql,
+    {
+        fn to_sql(&self) -> rusqlite::Result<ToSqlOutput<'_>> {
+            self.data.to_sql()
+        }
+    }
+
+    impl<U, T> FromSql for Usage<U, T>
+    where
+        T: FromSql,
+    {
+        fn column_result(value: ValueRef<'_>) -> FromSqlResult<Self> {
+            T::column_result(value).map(U::as_usage)
+        }
+    }
+}
+
+#[cfg(feature = "redis")]
+mod redis_impl {
+    use super::*;
+    use redis::{
+        FromRedisValue, NumericBehavior, ParsingError, RedisWrite, ToRedisArgs, ToSingleRedisArg,
+        Value,
+    };
+
+    /// Writes the same arguments as `T`:
+    /// ```
+    /// use redis::{ToRedisArgs, Value};
+    /// use usage::Usage;
+    ///
+    /// enum SessionId {}
+    ///
+    /// let session = Usage::<SessionId, String>::new("abc123".into());
+    /// assert_eq!(session.to_redis_args(), vec![b"abc123".to_vec()]);
+    ///
+    /// let value = Value::BulkString(session.to_redis_args().remove(0));
+    /// let parsed: Usage<SessionId, String> = redis::from_redis_value(value).unwrap();
+    /// assert_eq!(parsed, session);
+    /// ```
+    impl<U, T> ToRedisArgs for Usage<U, T>
+    where
+        T: ToRedisArgs,
+    {
+        fn write_redis_args<W>(&self, out: &mut W)
+        where
+            W: ?Sized + RedisWrite,
+        {
+            self.data.write_redis_args(out)
+        }
+
+        fn describe_numeric_behavior(&self) -> NumericBehavior {
+            self.data.describe_numeric_behavior()
+        }
+
+        fn num_of_args(&self) -> usize {
+            self.data.num_of_args()
+        }
+    }
+
+    impl<U, T> ToSingleRedisArg for Usage<U, T> where T: ToSingleRedisArg {}
+
+    /// Parses from the same values as `T`:
+    /// ```
+    /// use redis::Value;
+    /// use usage::Usage;
+    ///
+    /// enum Hits {}
+    ///
+    /// let hits: Usage<Hits, u64> = redis::from_redis_value(Value::Int(42)).unwrap();
+    /// assert_eq!(hits.data, 42);
+    ///
+    /// let hits: Usage<Hits, u64> = redis::from_redis_value_ref(&Value::SimpleString("7".into())).unwrap();
+    /// assert_eq!(hits.data, 7);
+    ///
+    /// assert!(redis::from_redis_value::<Usage<Hits, u64>>(Value::Nil).is_err());
+    /// ```
+    impl<U, T> FromRedisValue for Usage<U, T>
+    where
+        T: FromRedisValue,
+    {
+        fn from_redis_value_ref(v: &Value) -> Result<Self, ParsingError> {
+            T::from_redis_value_ref(v).map(U::as_usage)
+        }
+
+        fn from_redis_value(v: Value) -> Result<Self, ParsingError> {
+            T::from_redis_value(v).map(U::as_usage)
+        }
+    }
+}
+
+/// Strips module paths from each component of a type name, for integrations that name tagged types
+#[cfg(any(feature = "bevy_reflect", feature = "schemars"))]
+fn short_name(name: &str) -> String {
+    let mut short = String::with_capacity(name.len());
+    let mut segment = 0;
+
+    for (i, c) in name.char_indices() {
+        if c == ':' {
+            segment = i + 1;
+        } else if !(c.is_alphanumeric() || c == '_') {
+            short.push_str(&name[segment..i]);
+            short.push(c);
+            segment = i + c.len_utf8();
+        }
+    }
+
+    short.push_str(&name[segment..]);
+    short
+}
+
+#[cfg(feature = "bevy_reflect")]
+mod bevy_reflect_impl {
+    use super::*;
+    use bevy_reflect::{impl_reflect, utility::GenericTypePathCell, TypePath};
+
+    // Reflected as a struct with a single `data` field.
+    // `TypePath` is implemented manually so that tags need not implement it themselves.
+    impl_reflect!(
+        #[reflect(type_path = false, where U: 'static)]
+        #[type_path = "usage"]
+        struct Usage<U, T> {
+            pub data: T,
+            #[reflect(ignore)]
+            _phantom: PhantomData<fn(U) -> U>,
+        }
+    );
+
+    /// Includes the tag's type name, so that differently-tagged types are told apart:
+    /// ```
+    /// use bevy_reflect::{
+    ///     FromReflect, GetTypeRegistration, PartialReflect, Reflect, ReflectRef, TypePath,
+    ///     TypeRegistry,
+    /// };
+    /// use usage::Usage;
+    ///
+    /// #[derive(Debug, Default, Copy, Clone, PartialEq, Reflect)]
+    /// struct UVec2 {
+    ///     x: u32,
+    ///     y: u32,
+    /// }
+    ///
+    /// enum Window {}
+    /// enum Surface {}
+    ///
+    /// type WindowSize = Usage<Window, UVec2>;
+    /// type SurfaceSize = Usage<Surface, UVec2>;
+    ///
+    /// assert!(WindowSize::type_path().starts_with("usage::Usage<"));
+    /// assert!(WindowSize::type_path().contains("Window"));
+    /// assert_eq!(WindowSize::short_type_path(), "Usage<Window, UVec2>");
+    /// assert_ne!(WindowSize::type_path(), SurfaceSize::type_path());
+    ///
+    /// let mut size = WindowSize::new(UVec2 { x: 640, y: 480 });
+    ///
+    /// let ReflectRef::Struct(reflected) = size.reflect_ref() else {
+    ///     panic!("expected a struct");
+    /// };
+    /// let inner = reflected.field("data").unwrap();
+    /// assert_eq!(inner.try_downcast_ref::<UVec2>(), Some(&UVec2 { x: 640, y: 480 }));
+    ///
+    /// size.apply(&WindowSize::new(UVec2 { x: 800, y: 600 }));
+    /// assert_eq!(size.data, UVec2 { x: 800, y: 600 });
+    ///
+    /// let cloned = WindowSize::from_reflect(size.as_partial_reflect()).unwrap();
+    /// assert_eq!(cloned.data, size.data);
+    ///
+    /// let mut registry = TypeRegistry::default();
+    /// registry.register::<WindowSize>();
+    /// assert!(registry.get_with_type_path(WindowSize::type_path()).is_some());
+    /// assert!(registry.get(WindowSize::get_type_registration().type_id()).is_some());
+    /// ```
+    impl<U, T> TypePath for Usage<U, T>
+    where
+        U: 'static,
+        T: TypePath,
+    {
+        fn type_path() -> &'static str {
+            static CELL: GenericTypePathCell = GenericTypePathCell::new();
+            CELL.get_or_insert::<Self, _>(|| {
+                format!(
+                    "usage::Usage<{}, {}>",
+                    core::any::type_name::<U>(),
+                    T::type_path()
+                )
+            })
+        }
+
+        fn short_type_path() -> &'static str {
+            static CELL: GenericTypePathCell = GenericTypePathCell::new();
+            CELL.get_or_insert::<Self, _>(|| {
+                format!(
+                    "Usage<{}, {}>",
+                    super::short_name(core::any::type_name::<U>()),
+                    T::short_type_path()
+                )
+            })
+        }
+
+        fn type_ident() -> Option<&'static str> {
+            Some("Usage")
+        }
+
+        fn crate_name() -> Option<&'static str> {
+            Some("usage")
+        }
+
+        fn module_path() -> Option<&'static str> {
+            Some("usage")
+        }
+    }
+}
+
+#[cfg(feature = "futures")]
+mod futures_impl {
+    use super::*;
+    use core::task::{Context, Poll};
+    use futures::{Sink, Stream};
+
+    /// Polls the stream `T`:
+    /// ```
+    /// use futures::{executor::block_on, stream, Stream, StreamExt};
+    /// use usage::Usage;
+    ///
+    /// enum Events {}
+    ///
+    /// let events = Usage::<Events, _>::new(stream::iter(vec![1, 2, 3]));
+    /// assert_eq!(events.size_hint(), (3, Some(3)));
+    ///
+    /// let collected: Vec<i32> = block_on(events.collect());
+    /// assert_eq!(collected, [1, 2, 3]);
+    /// ```
+    impl<U, T> Stream for Usage<U, T>
+    where
+        T: Stream,
+    {
+        type Item = T::Item;
+
+        fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+            self.project_pin().poll_next(cx)
+        }
+
+        fn size_hint(&self) -> (usize, Option<usize>) {
+            self.data.size_hint()
+        }
+    }
+
+    /// Sends into the sink `T`:
+    /// ```
+    /// use futures::{channel::mpsc, executor::block_on, SinkExt, StreamExt};
+    /// use usage::Usage;
+    ///
+    /// enum Events {}
+    ///
+    /// let (tx, rx) = mpsc::unbounded::<i32>();
+    /// let mut tx = Usage::<Events, _>::new(tx);
+    /// let rx = Usage::<Events, _>::new(rx);
+    ///
+    /// block_on(async {
+    ///     tx.send(1).await.unwrap();
+    ///     tx.send(2).await.unwrap();
+    ///     tx.close().await.unwrap();
+    /// });
+    ///
+    /// assert_eq!(block_on(rx.collect::<Vec<_>>()), [1, 2]);
+    /// ```
+    impl<U, T, Item> Sink<Item> for Usage<U, T>
+    where
+        T: Sink<Item>,
+    {
+        type Error = T::Error;
+
+        fn poll_ready(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+            self.project_pin().poll_ready(cx)
+        }
+
+        fn start_send(self: Pin<&mut Self>, item: Item) -> Result<(), Self::Error> {
+            self.project_pin().start_send(item)
+        }
+
+        fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+            self.project_pin().poll_flush(cx)
+        }
+
+        fn poll_close(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+            self.project_pin().poll_close(cx)
+        }
+    }
+}
+
+#[cfg(feature = "tokio")]
+mod tokio_impl {
+    use super::*;
+    use core::task::{Context, Poll};
+    use tokio::io::{AsyncRead, AsyncWrite, ReadBuf};
+
+    /// Reads from `T`:
+    /// ```
+    /// use futures::executor::block_on;
+    /// use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    /// use usage::Usage;
+    ///
+    /// enum Upstream {}
+    ///
+    /// let (mut client, server) = tokio::io::duplex(64);
+    /// let mut server = Usage::<Upstream, _>::new(server);
+    ///
+    /// block_on(async {
+    ///     client.write_all(b"ping").await.unwrap();
+    ///
+    ///     let mut buf = [0; 4];
+    ///     server.read_exact(&mut buf).await.unwrap();
+    ///     assert_eq!(&buf, b"ping");
+    /// });
+    /// ```
+    impl<U, T> AsyncRead for Usage<U, T>
+    where
+        T: AsyncRead,
+    {
+        fn poll_read(
+            self: Pin<&mut Self>,
+            cx: &mut Context<'_>,
+            buf: &mut ReadBuf<'_>,
+        ) -> Poll<std::io::Result<()>> {
+            self.project_pin().poll_read(cx, buf)
+        }
+    }
+
+    /// Writes to `T`:
+    /// ```
+    /// use futures::executor::block_on;
+    /// use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    /// use usage::Usage;
+    ///
+    /// enum Upstream {}
+    ///
+    /// let (client, mut server) = tokio::io::duplex(64);
+    /// let mut client = Usage::<Upstream, _>::new(client);
+    ///
+    /// block_on(async {
+    ///     client.write_all(b"pong").await.unwrap();
+    ///     client.flush().await.unwrap();
+    ///     client.shutdown().await.unwrap();
+    ///
+    ///     let mut received = vec![];
+    ///     server.read_to_end(&mut received).await.unwrap();
+    ///     assert_eq!(received, b"pong");
+    /// });
+    /// ```
+    impl<U, T> AsyncWrite for Usage<U, T>
+    where
+        T: AsyncWrite,
+    {
+        fn poll_write(
+            self: Pin<&mut Self>,
+            cx: &mut Context<'_>,
+            buf: &[u8],
+        ) -> Poll<std::io::Result<usize>> {
+            self.project_pin().poll_write(cx, buf)
+        }
+
+        fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<std::io::Result<()>> {
+            self.project_pin().poll_flush(cx)
+        }
+
+        fn poll_shutdown(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<std::io::Result<()>> {
+            self.project_pin().poll_shutdown(cx)
+        }
+
+        fn poll_write_vectored(
+            self: Pin<&mut Self>,
+            cx: &mut Context<'_>,
+            bufs: &[std::io::IoSlice<'_>],
+        ) -> Poll<std::io::Result<usize>> {
+            self.project_pin().poll_write_vectored(cx, bufs)
+        }
+
+        fn is_write_vectored(&self) -> bool {
+            self.data.is_write_vectored()
+        }
+    }
+}
+
+#[cfg(feature = "rkyv")]
+mod rkyv_impl {
+    use super::*;
+    use rkyv::{
+        bytecheck::CheckBytes, rancor::Fallible, traits::CopyOptimization, Archive, Deserialize,
+        Place, Portable, Serialize,
+    };
+
+    // SAFETY: `Usage` is `repr(transparent)` over `T`, as its only other field is a zero-sized `PhantomData`.
+    unsafe impl<U, T> Portable for Usage<U, T> where T: Portable {}
+
+    /// Archives as `Usage<U, T::Archived>`, so the tag is kept in the archived form:
+    /// ```
+    /// use rkyv::rancor::Error;
+    /// use usage::Usage;
+    ///
+    /// enum Tag {}
+    ///
+    /// let value = Usage::<Tag, Vec<u32>>::new(vec![1, 2, 3]);
+    /// let bytes = rkyv::to_bytes::<Error>(&value).unwrap();
+    ///
+    /// let archived = unsafe { rkyv::access_unchecked::<rkyv::Archived<Usage<Tag, Vec<u32>>>>(&bytes) };
+    /// assert_eq!(archived.len(), 3);
+    /// assert_eq!(archived[1], 2);
+    ///
+    /// let deserialized = unsafe { rkyv::from_bytes_unchecked::<Usage<Tag, Vec<u32>>, Error>(&bytes) };
+    /// assert_eq!(deserialized.unwrap(), value);
+    /// ```
+    impl<U, T> Archive for Usage<U, T>
+    where
+        T: Archive,
+    {
+        // SAFETY: `Usage` shares the layout and padding of `T`.
+        const COPY_OPTIMIZATION: CopyOptimization<Self> =
+            unsafe { CopyOptimization::enable_if(T::COPY_OPTIMIZATION.is_enabled()) };
+
+        type Archived = Usage<U, T::Archived>;
+        type Resolver = T::Resolver;
+
+        fn resolve(&self, resolver: Self::Resolver, out: Place<Self::Archived>) {
+            // SAFETY: `Usage<U, T::Archived>` is `repr(transparent)` over `T::Archived`,
+            // so the place points to a valid `T::Archived`.
+            let out = unsafe { out.cast_unchecked::<T::Archived>() };
+            self.data.resolve(resolver, out)
+        }
+    }
+
+    impl<U, T, S> Serialize<S> for Usage<U, T>
+    where
+        T: Serialize<S>,
+        S: Fallible + ?Sized,
+    {
+        fn serialize(&self, serializer: &mut S) -> Result<Self::Resolver, S::Error> {
+            self.data.serialize(serializer)
+        }
+    }
+
+    impl<U, T, D> Deserialize<Usage<U, T>, D> for Usage<U, T::Archived>
+    where
+        T: Archive,
+        T::Archived: Deserialize<T, D>,
+        D: Fallible + ?Sized,
+    {
+        fn deserialize(&self, deserializer: &mut D) -> Result<Usage<U, T>, D::Error> {
+            self.data.deserialize(deserializer).map(U::as_usage)
+        }
+    }
+
+    /// Validates as `T`, allowing untrusted buffers to be accessed and deserialized safely:
+    /// ```
+    /// use rkyv::{rancor::Error, Archived};
+    /// use usage::Usage;
+    ///
+    /// enum Tag {}
+    ///
+    /// let value = Usage::<Tag, Vec<u32>>::new(vec![1, 2, 3]);
+    /// let mut bytes = rkyv::to_bytes::<Error>(&value).unwrap();
+    ///
+    /// let archived = rkyv::access::<Archived<Usage<Tag, Vec<u32>>>, Error>(&bytes).unwrap();
+    /// assert_eq!(archived.as_slice(), [1, 2, 3]);
+    ///
+    /// let deserialized = rkyv::from_bytes::<Usage<Tag, Vec<u32>>, Error>(&bytes).unwrap();
+    /// assert_eq!(deserialized, value);
+    ///
+    /// // Claim more elements than the buffer holds
+    /// let len = bytes.len();
+    /// bytes[len - 4..].copy_from_slice(&1000u32.to_le_bytes());
+    /// assert!(rkyv::access::<Archived<Usage<Tag, Vec<u32>>>, Error>(&bytes).is_err());
+    ///
+    /// let flag = rkyv::to_bytes::<Error>(&Usage::<Tag, bool>::new(true)).unwrap();
+    /// assert!(rkyv::from_bytes::<Usage<Tag, bool>, Error>(&flag).unwrap().data);
+    ///
+    /// let mut flag = flag.to_vec();
+    /// flag[0] = 2;
+    /// assert!(rkyv::from_bytes::<Usage<Tag, bool>, Error>(&flag).is_err());
+    /// ```
+    unsafe impl<U, T, C> CheckBytes<C> for Usage<U, T>
+    where
+        T: CheckBytes<C>,
+        C: Fallible + ?Sized,
+    {
+        unsafe fn check_bytes(value: *const Self, context: &mut C) -> Result<(), C::Error> {
+            // SAFETY: `Usage` is `repr(transparent)` over `T`, so a pointer that is
+            // aligned for and covers a `Usage<U, T>` is aligned for and covers a `T`.
+            unsafe { T::check_bytes(value.cast::<T>(), context) }
+        }
+    }
+}
+
+#[cfg(feature = "borsh")]
+mod borsh_impl {
+    use super::*;
+    use borsh::{
+        io::{Read, Result, Write},
+        BorshDeserialize, BorshSerialize,
+    };
+
+    /// Serializes transparently as `T`, without any tag bytes:
+    /// ```
+    /// use usage::Usage;
+    ///
+    /// enum AccountId {}
+    ///
+    /// let account = Usage::<AccountId, [u8; 32]>::new([7; 32]);
+    ///
+    /// let bytes = borsh::to_vec(&account).unwrap();
+    /// assert_eq!(bytes, borsh::to_vec(&[7u8; 32]).unwrap());
+    /// assert_eq!(bytes.len(), 32);
+    ///
+    /// let decoded: Usage<AccountId, [u8; 32]> = borsh::from_slice(&bytes).unwrap();
+    /// assert_eq!(decoded, account);
+    ///
+    /// assert!(borsh::from_slice::<Usage<AccountId, [u8; 32]>>(&bytes[..31]).is_err());
+    /// ```
+    impl<U, T> BorshSerialize for Usage<U, T>
+    where
+        T: BorshSerialize,
+    {
+        fn serialize<W: Write>(&self, writer: &mut W) -> Result<()> {
+            self.data.serialize(writer)
+        }
+    }
+
+    impl<U, T> BorshDeserialize for Usage<U, T>
+    where
+        T: BorshDeserialize,
+    {
+        fn deserialize(buf: &mut &[u8]) -> Result<Self> {
+            T::deserialize(buf).map(U::as_usage)
+        }
+
+        fn deserialize_reader<R: Read>(reader: &mut R) -> Result<Self> {
+            T::deserialize_reader(reader).map(U::as_usage)
+        }
+    }
+}
+
+#[cfg(feature = "schemars")]
+mod schemars_impl {
+    use super::*;
+    use alloc::{borrow::Cow, format};
+    use schemars::{JsonSchema, Schema, SchemaGenerator};
+
+    /// Uses the schema of `T`, named after both the tag and `T` so each `Usage` gets a distinct definition,
+    /// and titled after the tag unless `T` provides a title of its own:
+    /// ```
+    /// use schemars::{schema_for, JsonSchema};
+    /// use serde_json::json;
+    /// use usage::Usage;
+    ///
+    /// enum UserId {}
+    /// enum OrderId {}
+    ///
+    /// assert_eq!(<Usage<UserId, u64>>::schema_name(), "UserId_uint64");
+    /// assert_ne!(<Usage<UserId, u64>>::schema_id(), <Usage<OrderId, u64>>::schema_id());
+    ///
+    /// let schema = schema_for!(Usage<UserId, u64>);
+    /// assert_eq!(schema.get("title"), Some(&json!("UserId")));
+    /// assert_eq!(schema.get("type"), schema_for!(u64).get("type"));
+    /// assert_eq!(schema.get("format"), Some(&json!("uint64")));
+    ///
+    /// #[derive(JsonSchema)]
+    /// struct Order {
+    ///     id: Usage<OrderId, u64>,
+    ///     user: Usage<UserId, u64>,
+    /// }
+    ///
+    /// let schema = serde_json::to_value(schema_for!(Order)).unwrap();
+    /// assert_eq!(schema["properties"]["user"], json!({ "$ref": "#/$defs/UserId_uint64" }));
+    /// assert_eq!(schema["$defs"]["OrderId_uint64"]["title"], json!("OrderId"));
+    /// assert_eq!(schema["$defs"]["OrderId_uint64"]["type"], json!("integer"));
+    /// ```
+    ///
+    /// Different inner types under one tag get separate definitions, and `T`'s own title is kept:
+    /// ```
+    /// use schemars::{schema_for, JsonSchema};
+    /// use serde_json::json;
+    /// use usage::Usage;
+    ///
+    /// enum UserId {}
+    ///
+    /// #[derive(JsonSchema)]
+    /// #[schemars(title = "Legacy")]
+    /// struct LegacyId(u32);
+    ///
+    /// #[derive(JsonSchema)]
+    /// struct Accounts {
+    ///     numeric: Usage<UserId, u64>,
+    ///     named: Usage<UserId, String>,
+    ///     legacy: Usage<UserId, LegacyId>,
+    /// }
+    ///
+    /// let schema = serde_json::to_value(schema_for!(Accounts)).unwrap();
+    /// let defs = &schema["$defs"];
+    /// assert_eq!(schema["properties"]["numeric"], json!({ "$ref": "#/$defs/UserId_uint64" }));
+    /// assert_eq!(schema["properties"]["named"], json!({ "$ref": "#/$defs/UserId_string" }));
+    /// assert_eq!(defs["UserId_uint64"]["type"], json!("integer"));
+    /// assert_eq!(defs["UserId_string"]["type"], json!("string"));
+    /// assert_eq!(defs["UserId_LegacyId"]["title"], json!("Legacy"));
+    /// ```
+    impl<U, T> JsonSchema for Usage<U, T>
+    where
+        T: JsonSchema,
+    {
+        fn schema_name() -> Cow<'static, str> {
+            format!(
+                "{}_{}",
+                short_name(core::any::type_name::<U>()),
+                T::schema_name()
+            )
+            .into()
+        }
+
+        fn schema_id() -> Cow<'static, str> {
+            format!(
+                "usage::Usage<{}, {}>",
+                core::any::type_name::<U>(),
+                T::schema_id()
+            )
+            .into()
+        }
+
+        fn json_schema(generator: &mut SchemaGenerator) -> Schema {
+            let mut schema = T::json_schema(generator);
+            if schema.get("title").is_none() {
+                schema.insert(
+                    "title".into(),
+                    short_name(core::any::type_name::<U>()).into(),
+                );
+            }
+            schema
+        }
+    }
+}
+
+#[cfg(feature = "scale-codec")]
+mod scale_codec_impl {
+    use super::*;
+    use parity_scale_codec::{Decode, Encode, EncodeLike, Error, Input, MaxEncodedLen, Output};
+    use scale_info::{build::Fields, Path, Type, TypeInfo, TypeParameter};
+
+    /// Encodes transparently as `T`, so tagged values can stand in for their inner type in storage:
+    /// ```
+    /// use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
+    /// use usage::Usage;
+    ///
+    /// enum AccountId {}
+    ///
+    /// let account = Usage::<AccountId, u64>::new(42);
+    ///
+    /// let bytes = Encode::encode(&account);
+    /// assert_eq!(bytes, Encode::encode(&42u64));
+    /// assert_eq!(account.encoded_size(), 8);
+    /// assert_eq!(<Usage<AccountId, u64>>::max_encoded_len(), u64::max_encoded_len());
+    ///
+    /// let decoded = <Usage<AccountId, u64>>::decode(&mut &bytes[..]).unwrap();
+    /// assert_eq!(decoded, account);
+    ///
+    /// assert!(<Usage<AccountId, u64>>::decode(&mut &bytes[..7]).is_err());
+    /// ```
+    impl<U, T> Encode for Usage<U, T>
+    where
+        T: Encode,
+    {
+        fn size_hint(&self) -> usize {
+            self.data.size_hint()
+        }
+
+        fn encode_to<W: Output + ?Sized>(&self, dest: &mut W) {
+            self.data.encode_to(dest)
+        }
+
+        fn encode(&self) -> Vec<u8> {
+            self.data.encode()
+        }
+
+        fn using_encoded<R, F: FnOnce(&[u8]) -> R>(&self, f: F) -> R {
+            self.data.using_encoded(f)
+        }
+
+        fn encoded_size(&self) -> usize {
+            self.data.encoded_size()
+        }
+    }
+
+    impl<U, T> Decode for Usage<U, T>
+    where
+        T: Decode,
+    {
+        fn decode<I: Input>(input: &mut I) -> Result<Self, Error> {
+            T::decode(input).map(U::as_usage)
+        }
+
+        fn skip<I: Input>(input: &mut I) -> Result<(), Error> {
+            T::skip(input)
+        }
+
+        fn encoded_fixed_size() -> Option<usize> {
+            T::encoded_fixed_size()
+        }
+    }
+
+    impl<U, T> MaxEncodedLen for Usage<U, T>
+    where
+        T: MaxEncodedLen,
+    {
+        fn max_encoded_len() -> usize {
+            T::max_encoded_len()
+        }
+    }
+
+    impl<U, T> EncodeLike for Usage<U, T> where T: Encode {}
+
+    /// A `Usage` encodes like its inner type, so it can be passed where `T` is expected:
+    /// ```
+    /// use parity_scale_codec::{Encode, EncodeLike};
+    /// use usage::Usage;
+    ///
+    /// enum Balance {}
+    ///
+    /// fn store<K: EncodeLike<u128>>(value: K) -> Vec<u8> {
+    ///     value.encode()
+    /// }
+    ///
+    /// assert_eq!(store(Usage::<Balance, u128>::new(5)), store(5u128));
+    /// ```
+    impl<U, T> EncodeLike<T> for Usage<U, T> where T: Encode {}
+
+    /// Describes a single-field composite over `T`, pathed under `usage::Usage` after the tag
+    /// so metadata tooling keeps tagged types apart.
+    ///
+    /// Tags need not implement `TypeInfo`, so the tag segments are derived from
+    /// [`core::any::type_name`]. They are best-effort: the exact segments may change between
+    /// compiler versions and are not part of the metadata contract, so tooling should key on the
+    /// registered type rather than on the path spelling:
+    /// ```
+    /// use scale_info::TypeInfo;
+    /// use usage::Usage;
+    ///
+    /// enum AccountId {}
+    /// enum AssetId {}
+    ///
+    /// let account = <Usage<AccountId, u64>>::type_info();
+    /// let asset = <Usage<AssetId, u64>>::type_info();
+    ///
+    /// assert_eq!(account.path.segments[..2], ["usage", "Usage"]);
+    /// assert_eq!(account.path.ident(), Some("AccountId"));
+    /// assert_eq!(asset.path.ident(), Some("AssetId"));
+    /// assert_ne!(account.path, asset.path);
+    /// assert_eq!(account.type_params[0].name, "T");
+    ///
+    /// // Generic tags keep their arguments in the path
+    /// let some = <Usage<Option<AccountId>, u64>>::type_info();
+    /// let other = <Usage<Option<AssetId>, u64>>::type_info();
+    /// assert!(some.path.segments.contains(&"Option"));
+    /// assert_ne!(some.path, other.path);
+    ///
+    /// // Tags without a nameable path fall back to `usage::Usage`
+    /// let info = <Usage<(), u64>>::type_info();
+    /// assert_eq!(info.path.segments, ["usage", "Usage"]);
+    /// ```
+    ///
+    /// The inner type is registered as a type parameter, keeping different `T`s under one tag apart,
+    /// and a `Usage` never shares its path with the tag itself:
+    /// ```
+    /// use scale_info::{meta_type, Registry, TypeInfo};
+    /// use usage::Usage;
+    ///
+    /// #[derive(TypeInfo)]
+    /// struct AccountId;
+    ///
+    /// let numeric = <Usage<AccountId, u32>>::type_info();
+    /// let named = <Usage<AccountId, String>>::type_info();
+    /// assert_eq!(numeric.path, named.path);
+    /// assert_ne!(numeric.type_params, named.type_params);
+    /// assert_ne!(numeric, named);
+    ///
+    /// let tag = AccountId::type_info();
+    /// assert_ne!(tag.path, numeric.path);
+    /// assert_ne!(tag, numeric);
+    ///
+    /// let mut registry = Registry::new();
+    /// let ids = [
+    ///     registry.register_type(&meta_type::<Usage<AccountId, u32>>()),
+    ///     registry.register_type(&meta_type::<Usage<AccountId, String>>()),
+    ///     registry.register_type(&meta_type::<AccountId>()),
+    /// ];
+    /// assert_ne!(ids[0], ids[1]);
+    /// assert_ne!(ids[0], ids[2]);
+    /// ```
+    impl<U, T> TypeInfo for Usage<U, T>
+    where
+        U: 'static,
+        T: TypeInfo + 'static,
+    {
+        type Identity = Self;
+
+        fn type_info() -> Type {
+            // Only ASCII identifier runs from the tag's name are kept, so every segment is valid
+            let tag = core::any::type_name::<U>()
+                .split(|c: char| !(c.is_ascii_alphanumeric() || c == '_'))
+                .filter(|segment| {
+                    segment.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_')
+                });
+            let path = Path::from_segments_unchecked(["usage", "Usage"].into_iter().chain(tag));
+
+            Type::builder()
+                .path(path)
+                .type_params([TypeParameter::new("T", Some(scale_info::meta_type::<T>()))])
+                .composite(Fields::unnamed().field(|f| f.ty::<T>()))
+        }
+    }
+}
+
+#[cfg(feature = "defmt")]
+mod defmt_impl {
+    use super::*;
+    use defmt::{Format, Formatter};
+
+    /// Formats transparently as `T`; the tag name is left out to keep frames small:
+    /// ```
+    /// use core::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+    /// use usage::Usage;
+    ///
+    /// static WRITTEN: AtomicUsize = AtomicUsize::new(0);
+    /// static FORMATTED: AtomicBool = AtomicBool::new(false);
+    ///
+    /// #[defmt::global_logger]
+    /// struct Logger;
+    ///
+    /// unsafe impl defmt::Logger for Logger {
+    ///     fn acquire() {}
+    ///     unsafe fn flush() {}
+    ///     unsafe fn release() {}
+    ///     unsafe fn write(bytes: &[u8]) {
+    ///         WRITTEN.fetch_add(bytes.len(), Ordering::Relaxed);
+    ///     }
+    /// }
+    /// # defmt::timestamp!("{=u32}", 0);
+    ///
+    /// struct Status(u32);
+    ///
+    /// impl defmt::Format for Status {
+    ///     fn format(&self, fmt: defmt::Formatter<'_>) {
+    ///         FORMATTED.store(true, Ordering::Relaxed);
+    ///         defmt::write!(fmt, "Status({=u32:#x})", self.0)
+    ///     }
+    /// }
+    ///
+    /// enum Reg {}
+    ///
+    /// let status = Usage::<Reg, Status>::new(Status(0xDEAD_BEEF));
+    /// defmt::println!("status = {}", status);
+    ///
+    /// assert!(FORMATTED.load(Ordering::Relaxed));
+    /// assert!(WRITTEN.load(Ordering::Relaxed) > 0);
+    /// ```
+    impl<U, T> Format for Usage<U, T>
+    where
+        T: Format,
+    {
+        fn format(&self, fmt: Formatter<'_>) {
+            self.data.format(fmt)
+        }
+    }
+}
+
+#[cfg(feature = "ufmt")]
+mod ufmt_impl {
+    use super::*;
+    use ufmt::{uDebug, uDisplay, uWrite, Formatter};
+
+    /// Displays transparently as `T`, without allocating:
+    /// ```
+    /// use core::convert::Infallible;
+    /// use ufmt::{uWrite, uwrite};
+    /// use usage::Usage;
+    ///
+    /// struct Buf {
+    ///     bytes: [u8; 32],
+    ///     len: usize,
+    /// }
+    ///
+    /// impl uWrite for Buf {
+    ///     type Error = Infallible;
+    ///
+    ///     fn write_str(&mut self, s: &str) -> Result<(), Infallible> {
+    ///         self.bytes[self.len..self.len + s.len()].copy_from_slice(s.as_bytes());
+    ///         self.len += s.len();
+    ///         Ok(())
+    ///     }
+    /// }
+    ///
+    /// enum Reg {}
+    ///
+    /// let reg = Usage::<Reg, u32>::new(1234);
+    ///
+    /// let mut buf = Buf { bytes: [0; 32], len: 0 };
+    /// uwrite!(buf, "reg = {}", reg).unwrap();
+    /// assert_eq!(&buf.bytes[..buf.len], b"reg = 1234");
+    /// ```
+    impl<U, T> uDisplay for Usage<U, T>
+    where
+        T: uDisplay,
+    {
+        fn fmt<W>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error>
+        where
+            W: uWrite + ?Sized,
+        {
+            self.data.fmt(f)
+        }
+    }
+
+    /// Debug-formats transparently as `T`:
+    /// ```
+    /// use core::convert::Infallible;
+    /// use ufmt::{uWrite, uwrite};
+    /// use usage::Usage;
+    ///
+    /// # struct Buf {
+    /// #     bytes: [u8; 32],
+    /// #     len: usize,
+    /// # }
+    /// #
+    /// # impl uWrite for Buf {
+    /// #     type Error = Infallible;
+    /// #
+    /// #     fn write_str(&mut self, s: &str) -> Result<(), Infallible> {
+    /// #         self.bytes[self.len..self.len + s.len()].copy_from_slice(s.as_bytes());
+    /// #         self.len += s.len();
+    /// #         Ok(())
+    /// #     }
+    /// # }
+    ///
+    /// enum Reading {}
+    ///
+    /// let reading = Usage::<Reading, (u8, i16)>::new((3, -40));
+    ///
+    /// let mut buf = Buf { bytes: [0; 32], len: 0 };
+    /// uwrite!(buf, "{:?}", reading).unwrap();
+    /// assert_eq!(&buf.bytes[..buf.len], b"(3, -40)");
+    /// ```
+    impl<U, T> uDebug for Usage<U, T>
+    where
+        T: uDebug,
+    {
+        fn fmt<W>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error>
+        where
+            W: uWrite + ?Sized,
+        {
+            self.data.fmt(f)
+        }
+    }
+}
+
+#[cfg(feature = "bincode")]
+mod bincode_impl {
+    use super::*;
+    use bincode::{
+        de::{BorrowDecoder, Decoder},
+        enc::Encoder,
+        error::{DecodeError, EncodeError},
+        BorrowDecode, Decode, Encode,
+    };
+
+    /// Encodes transparently as `T`, without going through serde:
+    /// ```
+    /// use usage::Usage;
+    ///
+    /// enum Payload {}
+    ///
+    /// let config = bincode::config::standard();
+    /// let payload = Usage::<Payload, Vec<u8>>::new(vec![1, 2, 3]);
+    ///
+    /// let bytes = bincode::encode_to_vec(&payload, config).unwrap();
+    /// assert_eq!(bytes, bincode::encode_to_vec(vec![1u8, 2, 3], config).unwrap());
+    ///
+    /// let (decoded, len): (Usage<Payload, Vec<u8>>, usize) =
+    ///     bincode::decode_from_slice(&bytes, config).unwrap();
+    /// assert_eq!(decoded, payload);
+    /// assert_eq!(len, bytes.len());
+    ///
+    /// let borrowed: (Usage<Payload, &[u8]>, usize) =
+    ///     bincode::borrow_decode_from_slice(&bytes, config).unwrap();
+    /// assert_eq!(borrowed.0.data, [1, 2, 3]);
+    /// ```
+    impl<U, T> Encode for Usage<U, T>
+    where
+        T: Encode,
+    {
+        fn encode<E: Encoder>(&self, encoder: &mut E) -> Result<(), EncodeError> {
+            self.data.encode(encoder)
+        }
+    }
+
+    impl<U, T, Context> Decode<Context> for Usage<U, T>
+    where
+        T: Decode<Context>,
+    {
+        fn decode<D: Decoder<Context = Context>>(decoder: &mut D) -> Result<Self, DecodeError> {
+            T::decode(decoder).map(U::as_usage)
+        }
+    }
+
+    impl<'de, U, T, Context> BorrowDecode<'de, Context> for Usage<U, T>
+    where
+        T: BorrowDecode<'de, Context>,
+    {
+        fn borrow_decode<D: BorrowDecoder<'de, Context = Context>>(
+            decoder: &mut D,
+        ) -> Result<Self, DecodeError> {
+            T::borrow_decode(decoder).map(U::as_usage)
+        }
+    }
+}
+
+#[cfg(feature = "pyo3")]
+mod pyo3_impl {
+    use super::*;
+    use pyo3::{Borrowed, FromPyObject, IntoPyObject, PyAny, Python};
+
+    /// Converts to and from Python as `T` does, so tagged IDs cross the boundary as plain values.
+    impl<'py, U, T> IntoPyObject<'py> for Usage<U, T>
+    where
+        T: IntoPyObject<'py>,
+    {
+        type Target = T::Target;
+        type Output = T::Output;
+        type Error = T::Error;
+
+        fn into_pyobject(self, py: Python<'py>) -> Result<Self::Output, Self::Error> {
+            self.data.into_pyobject(py)
+        }
+    }
+
+    impl<'a, 'py, U, T> IntoPyObject<'py> for &'a Usage<U, T>
+    where
+        &'a T: IntoPyObject<'py>,
+    {
+        type Target = <&'a T as IntoPyObject<'py>>::Target;
+        type Output = <&'a T as IntoPyObject<'py>>::Output;
+        type Error = <&'a T as IntoPyObject<'py>>::Error;
+
+        fn into_pyobject(self, py: Python<'py>) -> Result<Self::Output, Self::Error> {
+            self.data.into_pyobject(py)
+        }
+    }
+
+    impl<'a, 'py, U, T> FromPyObject<'a, 'py> for Usage<U, T>
+    where
+        T: FromPyObject<'a, 'py>,
+    {
+        type Error = T::Error;
+
+        fn extract(obj: Borrowed<'a, 'py, PyAny>) -> Result<Self, Self::Error> {
+            T::extract(obj).map(U::as_usage)
+        }
+    }
+}
+
+#[cfg(feature = "valuable")]
+mod valuable_impl {
+    use super::*;
+    use valuable::{Valuable, Value, Visit};
+
+    /// Presents the inner value to visitors, so tagged fields are recorded structurally as `T`:
+    /// ```
+    /// use usage::Usage;
+    /// use valuable::{Slice, Valuable, Value, Visit};
+    ///
+    /// #[derive(Default)]
+    /// struct Record {
+    ///     values: Vec<String>,
+    ///     primitive_slices: usize,
+    /// }
+    ///
+    /// impl Visit for Record {
+    ///     fn visit_value(&mut self, value: Value<'_>) {
+    ///         match value {
+    ///             Value::Listable(list) => list.visit(self),
+    ///             value => self.values.push(format!("{:?}", value)),
+    ///         }
+    ///     }
+    ///
+    ///     fn visit_primitive_slice(&mut self, slice: Slice<'_>) {
+    ///         self.primitive_slices += 1;
+    ///         for value in slice {
+    ///             self.visit_value(value);
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// enum RequestId {}
+    ///
+    /// let id = Usage::<RequestId, u64>::new(7);
+    /// assert!(matches!(id.as_value(), Value::U64(7)));
+    ///
+    /// let mut record = Record::default();
+    /// valuable::visit(&id, &mut record);
+    /// assert_eq!(record.values, ["7"]);
+    ///
+    /// let bytes = [Usage::<RequestId, u8>::new(1), Usage::new(2)];
+    /// let mut record = Record::default();
+    /// valuable::visit(&&bytes[..], &mut record);
+    /// assert_eq!(record.values, ["1", "2"]);
+    /// assert_eq!(record.primitive_slices, 1);
+    /// ```
+    impl<U, T> Valuable for Usage<U, T>
+    where
+        T: Valuable,
+    {
+        fn as_value(&self) -> Value<'_> {
+            self.data.as_value()
+        }
+
+        fn visit(&self, visit: &mut dyn Visit) {
+            self.data.visit(visit)
+        }
+
+        fn visit_slice(slice: &[Self], visit: &mut dyn Visit)
+        where
+            Self: Sized,
+        {
+            T::visit_slice(Usage::as_inner_slice(slice), visit)
+        }
+    }
+}
+
+#[cfg(feature = "glam")]
+mod glam_impl {
+    use super::*;
+    use glam::{
+        DVec2, DVec3, DVec4, IVec2, IVec3, IVec4, UVec2, UVec3, UVec4, Vec2, Vec3, Vec3A, Vec4,
+    };
+
+    macro_rules! impl_glam_conversions {
+        ($($(#[$attr:meta])* $vec:ident => $tuple:ty),* $(,)?) => {
+            $(
+                $(#[$attr])*
+                impl<U> From<Usage<U, $vec>> for $vec {
+                    fn from(usage: Usage<U, $vec>) -> Self {
+                        usage.data
+                    }
+                }
+
+                impl<U> From<Usage<U, $tuple>> for Usage<U, $vec> {
+                    fn from(usage: Usage<U, $tuple>) -> Self {
+                        usage.map(Into::into)
+                    }
+                }
+
+                impl<U> From<Usage<U, $vec>> for Usage<U, $tuple> {
+                    fn from(usage: Usage<U, $vec>) -> Self {
+                        usage.map(Into::into)
+                    }
+                }
+            )*
+        };
+    }
+
+    impl_glam_conversions!(
+        /// Tagged glam vectors convert back to the bare type, and bare vectors are tagged via
+        /// `From`, [`IntoUsage`] or [`AsUsage`]:
+        /// ```
+        /// use glam::UVec2;
+        /// use usage::{AsUsage, IntoUsage, Usage};
+        ///
+        /// enum Window {}
+        ///
+        /// type WindowSize = Usage<Window, UVec2>;
+        ///
+        /// let size: WindowSize = UVec2::new(640, 480).into();
+        /// assert_eq!(size, UVec2::new(640, 480).usage::<Window>());
+        /// assert_eq!(size, Window::as_usage(UVec2::new(640, 480)));
+        ///
+        /// let raw: UVec2 = size.into();
+        /// assert_eq!(raw, UVec2::new(640, 480));
+        /// assert_eq!(WindowSize::from(raw), size);
+        /// ```
+        ///
+        /// Tuple-backed usages convert to and from their glam counterpart, keeping the tag:
+        /// ```
+        /// use glam::{UVec2, Vec3};
+        /// use usage::Usage;
+        ///
+        /// enum Window {}
+        /// enum Position {}
+        ///
+        /// let size = Usage::<Window, (u32, u32)>::new((640, 480));
+        /// let vec: Usage<Window, UVec2> = size.into();
+        /// assert_eq!(vec.data, UVec2::new(640, 480));
+        /// assert_eq!(Usage::<Window, (u32, u32)>::from(vec), size);
+        ///
+        /// let position: Usage<Position, Vec3> = Usage::<Position, _>::new((1.0, 2.0, 3.0)).into();
+        /// assert_eq!(position.data, Vec3::new(1.0, 2.0, 3.0));
+        /// ```
+        Vec2 => (f32, f32),
+        Vec3 => (f32, f32, f32),
+        Vec3A => (f32, f32, f32),
+        Vec4 => (f32, f32, f32, f32),
+        DVec2 => (f64, f64),
+        DVec3 => (f64, f64, f64),
+        DVec4 => (f64, f64, f64, f64),
+        IVec2 => (i32, i32),
+        IVec3 => (i32, i32, i32),
+        IVec4 => (i32, i32, i32, i32),
+        UVec2 => (u32, u32),
+        UVec3 => (u32, u32, u32),
+        UVec4 => (u32, u32, u32, u32),
+    );
+}
+
+#[cfg(feature = "mint")]
+mod mint_impl {
+    use super::*;
+    use mint::{
+        ColumnMatrix2, ColumnMatrix2x3, ColumnMatrix2x4, ColumnMatrix3, ColumnMatrix3x2,
+        ColumnMatrix3x4, ColumnMatrix4, ColumnMatrix4x2, ColumnMatrix4x3, EulerAngles, IntoMint,
+        Point2, Point3, Quaternion, RowMatrix2, RowMatrix2x3, RowMatrix2x4, RowMatrix3,
+        RowMatrix3x2, RowMatrix3x4, RowMatrix4, RowMatrix4x2, RowMatrix4x3, Vector2, Vector3,
+        Vector4,
+    };
+
+    macro_rules! impl_mint_conversions {
+        ($($(#[$attr:meta])* $mint:ident),* $(,)?) => {
+            $(
+                $(#[$attr])*
+                impl<U, T, S> From<Usage<U, T>> for $mint<S>
+                where
+                    T: Into<$mint<S>>,
+                {
+                    fn from(usage: Usage<U, T>) -> Self {
+                        usage.data.into()
+                    }
+                }
+            )*
+        };
+    }
+
+    impl_mint_conversions!(
+        /// Tagged values convert into any `mint` type their inner value does.
+        ///
+        /// The blanket `From<T>` for `Usage<U, T>` rules out a generic conversion back,
+        /// so incoming `mint` values are tagged first and then mapped into `T`:
+        /// ```
+        /// use glam::Vec2;
+        /// use usage::Usage;
+        ///
+        /// enum Position {}
+        ///
+        /// let position = Usage::<Position, Vec2>::new(Vec2::new(1.0, 2.0));
+        ///
+        /// let point: mint::Point2<f32> = position.into();
+        /// assert_eq!((point.x, point.y), (1.0, 2.0));
+        ///
+        /// let tagged: Usage<Position, mint::Point2<f32>> = point.into();
+        /// let back: Usage<Position, Vec2> = tagged.map(Vec2::from);
+        /// assert_eq!(back, position);
+        /// ```
+        Vector2,
+        Vector3,
+        Vector4,
+        Point2,
+        Point3,
+        Quaternion,
+        RowMatrix2,
+        RowMatrix2x3,
+        RowMatrix2x4,
+        RowMatrix3x2,
+        RowMatrix3,
+        RowMatrix3x4,
+        RowMatrix4x2,
+        RowMatrix4x3,
+        RowMatrix4,
+        ColumnMatrix2,
+        ColumnMatrix2x3,
+        ColumnMatrix2x4,
+        ColumnMatrix3x2,
+        ColumnMatrix3,
+        ColumnMatrix3x4,
+        ColumnMatrix4x2,
+        ColumnMatrix4x3,
+        ColumnMatrix4,
+    );
+
+    impl<U, T, S, B> From<Usage<U, T>> for EulerAngles<S, B>
+    where
+        T: Into<EulerAngles<S, B>>,
+    {
+        fn from(usage: Usage<U, T>) -> Self {
+            usage.data.into()
+        }
+    }
+
+    /// Tagged values share their inner value's `mint` type:
+    /// ```
+    /// use glam::Vec3;
+    /// use mint::IntoMint;
+    /// use usage::Usage;
+    ///
+    /// enum Velocity {}
+    ///
+    /// fn to_mint<M: IntoMint>(value: M) -> M::MintType {
+    ///     value.into()
+    /// }
+    ///
+    /// let velocity = Usage::<Velocity, Vec3>::new(Vec3::new(0.0, -9.8, 0.0));
+    /// let vector: mint::Vector3<f32> = to_mint(velocity);
+    /// assert_eq!(vector.y, -9.8);
+    /// ```
+    impl<U, T> IntoMint for Usage<U, T>
+    where
+        T: IntoMint,
+        T::MintType: From<Self>,
+    {
+        type MintType = T::MintType;
+    }
+}
+
+#[cfg(feature = "bytes")]
+mod bytes_impl {
+    use super::*;
+    use bytes::{buf::UninitSlice, Buf, BufMut, Bytes};
+
+    /// Reads through to the inner buffer:
+    /// ```
+    /// use bytes::{Buf, Bytes};
+    /// use usage::Usage;
+    ///
+    /// enum Frame {}
+    ///
+    /// let mut frame = Usage::<Frame, Bytes>::new(Bytes::from_static(&[0, 1, 2, 3, 4]));
+    ///
+    /// assert_eq!(frame.remaining(), 5);
+    /// assert_eq!(frame.get_u8(), 0);
+    /// assert_eq!(frame.chunk(), [1, 2, 3, 4]);
+    ///
+    /// frame.advance(1);
+    /// assert_eq!(frame.get_u16(), 0x0203);
+    /// assert_eq!(frame.copy_to_bytes(1), [4][..]);
+    /// assert!(!frame.has_remaining());
+    /// ```
+    impl<U, T> Buf for Usage<U, T>
+    where
+        T: Buf,
+    {
+        fn remaining(&self) -> usize {
+            self.data.remaining()
+        }
+
+        fn chunk(&self) -> &[u8] {
+            self.data.chunk()
+        }
+
+        #[cfg(feature = "std")]
+        fn chunks_vectored<'a>(&'a self, dst: &mut [std::io::IoSlice<'a>]) -> usize {
+            self.data.chunks_vectored(dst)
+        }
+
+        fn advance(&mut self, cnt: usize) {
+            self.data.advance(cnt)
+        }
+
+        fn copy_to_bytes(&mut self, len: usize) -> Bytes {
+            self.data.copy_to_bytes(len)
+        }
+    }
+
+    /// Writes through to the inner buffer:
+    /// ```
+    /// use bytes::{BufMut, BytesMut};
+    /// use usage::Usage;
+    ///
+    /// enum Frame {}
+    ///
+    /// let mut frame = Usage::<Frame, BytesMut>::new(BytesMut::new());
+    ///
+    /// frame.put_u8(0xff);
+    /// frame.put_u16(0x0102);
+    /// frame.put_slice(b"ok");
+    /// assert_eq!(&frame[..], [0xff, 0x01, 0x02, b'o', b'k']);
+    ///
+    /// let mut buf = [0; 2];
+    /// let mut fixed = Usage::<Frame, &mut [u8]>::new(&mut buf[..]);
+    /// assert_eq!(fixed.remaining_mut(), 2);
+    /// fixed.put_u16_le(0x0102);
+    /// assert_eq!(fixed.remaining_mut(), 0);
+    /// assert_eq!(buf, [0x02, 0x01]);
+    /// ```
+    unsafe impl<U, T> BufMut for Usage<U, T>
+    where
+        T: BufMut,
+    {
+        fn remaining_mut(&self) -> usize {
+            self.data.remaining_mut()
+        }
+
+        unsafe fn advance_mut(&mut self, cnt: usize) {
+            // SAFETY: The caller upholds `advance_mut`'s contract for `T`.
+            unsafe { self.data.advance_mut(cnt) }
+        }
+
+        fn chunk_mut(&mut self) -> &mut UninitSlice {
+            self.data.chunk_mut()
+        }
+
+        fn put_slice(&mut self, src: &[u8]) {
+            self.data.put_slice(src)
+        }
+    }
+}
+
+// Data access traits
+// `Borrow` requires borrowed forms to hash identically, which `tagged-hash` rules out
+#[cfg(not(feature = "tagged-hash"))]
+impl<U, T> Borrow<T> for Usage<U, T> {
+    fn borrow(&self) -> &T {
+        &self.data
+    }
+}
+
+#[cfg(not(feature = "tagged-hash"))]
+impl<U, T> BorrowMut<T> for Usage<U, T> {
+    fn borrow_mut(&mut self) -> &mut T {
+        &mut self.data
+    }
+}
+
+/// Allows `Usage<U, T>` to be passed where `impl AsRef<T>` is expected:
+/// ```
+/// use usage::Usage;
+///
+/// enum Tag {}
+///
+/// fn len(s: impl AsRef<String>) -> usize {
+///     s.as_ref().len()
+/// }
+///
+/// fn push(mut s: impl AsMut<String>) {
+///     s.as_mut().push('!');
+/// }
+///
+/// let mut usage = Usage::<Tag, String>::new("hello".into());
+/// push(&mut usage);
+/// assert_eq!(usage.data, "hello!");
+/// assert_eq!(len(usage), 6);
+/// ```
+impl<U, T> AsRef<T> for Usage<U, T> {
+    fn as_ref(&self) -> &T {
+        &self.data
+    }
+}
+
+impl<U, T> AsMut<T> for Usage<U, T> {
+    fn as_mut(&mut self) -> &mut T {
+        &mut self.data
+    }
+}
+
+// As with `AsRef` below, a blanket `Borrow<Q>` impl would overlap with `Borrow<T>`
+// and the standard library's `impl<T> Borrow<T> for T`.
+macro_rules! impl_borrow_passthrough {
+    ($($(#[$attr:meta])* [$($gen:tt)*] $inner:ty => $target:ty),* $(,)?) => {
+        $(
+            $(#[$attr])*
+            #[cfg(not(feature = "tagged-hash"))]
+            impl<U, $($gen)*> Borrow<$target> for Usage<U, $inner> {
+                fn borrow(&self) -> &$target {
+                    self.data.borrow()
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! impl_borrow_mut_passthrough {
+    ($($(#[$attr:meta])* [$($gen:tt)*] $inner:ty => $target:ty),* $(,)?) => {
+        $(
+            $(#[$attr])*
+            #[cfg(not(feature = "tagged-hash"))]
+            impl<U, $($gen)*> BorrowMut<$target> for Usage<U, $inner> {
+                fn borrow_mut(&mut self) -> &mut $target {
+                    self.data.borrow_mut()
+                }
+            }
+        )*
+    };
+}
+
+impl_borrow_passthrough!(
+    /// `Borrow` passthrough is provided for the standard library's own `Borrow` impls,
+    /// allowing map lookups by the inner type's borrowed form
+    /// (these are unavailable with the `tagged-hash` feature):
+    /// ```
+    /// use std::collections::{BTreeMap, HashMap};
+    /// use std::hash::{BuildHasher, RandomState};
+    /// use usage::Usage;
+    ///
+    /// enum Key {}
+    ///
+    /// let state = RandomState::new();
+    /// let key = Usage::<Key, String>::new("foo".into());
+    /// assert_eq!(state.hash_one(&key), state.hash_one("foo"));
+    ///
+    /// let mut map = HashMap::<Usage<Key, String>, i32>::new();
+    /// map.insert(key, 1);
+    ///
+    /// assert_eq!(map.get("foo"), Some(&1));
+    /// assert_eq!(map.get(&"foo".to_string()), Some(&1));
+    /// assert_eq!(map.get("bar"), None);
+    ///
+    /// let mut map = BTreeMap::<Usage<Key, String>, i32>::new();
+    /// map.insert(Usage::new("foo".into()), 1);
+    ///
+    /// assert_eq!(map.get("foo"), Some(&1));
+    /// assert_eq!(map.get("bar"), None);
+    /// ```
+    #[cfg(feature = "alloc")]
+    [] String => str,
+    #[cfg(feature = "std")]
+    [] std::path::PathBuf => std::path::Path,
+    #[cfg(feature = "std")]
+    [] std::ffi::OsString => std::ffi::OsStr,
+    #[cfg(feature = "alloc")]
+    [] alloc::ffi::CString => core::ffi::CStr,
+    #[cfg(feature = "alloc")]
+    [E] Vec<E> => [E],
+    [E, const N: usize] [E; N] => [E],
+    #[cfg(feature = "alloc")]
+    [E: ?Sized] Box<E> => E,
+    #[cfg(feature = "alloc")]
+    [E: ?Sized] alloc::rc::Rc<E> => E,
+    #[cfg(feature = "alloc")]
+    [E: ?Sized] alloc::sync::Arc<E> => E,
+);
+
+impl_borrow_mut_passthrough!(
+    #[cfg(feature = "alloc")]
+    [] String => str,
+    #[cfg(feature = "alloc")]
+    [E] Vec<E> => [E],
+    [E, const N: usize] [E; N] => [E],
+    #[cfg(feature = "alloc")]
+    [E: ?Sized] Box<E> => E,
+);
+
+// `impl<U, T, X> AsRef<X> for Usage<U, T> where T: AsRef<X>` would overlap with `AsRef<T>`,
+// so passthrough is provided for the standard library's own `AsRef` / `AsMut` impls instead.
+macro_rules! impl_as_ref_passthrough {
+    ($($(#[$attr:meta])* [$($gen:tt)*] $inner:ty => $target:ty),* $(,)?) => {
+        $(
+            $(#[$attr])*
+            impl<U, $($gen)*> AsRef<$target> for Usage<U, $inner> {
+                fn as_ref(&self) -> &$target {
+                    self.data.as_ref()
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! impl_as_mut_passthrough {
+    ($($(#[$attr:meta])* [$($gen:tt)*] $inner:ty => $target:ty),* $(,)?) => {
+        $(
+            $(#[$attr])*
+            impl<U, $($gen)*> AsMut<$target> for Usage<U, $inner> {
+                fn as_mut(&mut self) -> &mut $target {
+                    self.data.as_mut()
+                }
+            }
+        )*
+    };
+}
+
+impl_as_ref_passthrough!(
+    /// `AsRef` passthrough is provided for the standard library's own `AsRef` impls:
+    /// ```
+    /// use std::path::{Path, PathBuf};
+    /// use usage::Usage;
+    ///
+    /// enum Name {}
+    ///
+    /// fn shout(s: impl AsRef<str>) -> String {
+    ///     s.as_ref().to_uppercase()
+    /// }
+    ///
+    /// fn exists(p: impl AsRef<Path>) -> bool {
+    ///     p.as_ref().exists()
+    /// }
+    ///
+    /// let name = Usage::<Name, String>::new("ferris".into());
+    /// let _: &[u8] = AsRef::<[u8]>::as_ref(&name);
+    /// assert_eq!(shout(&name), "FERRIS");
+    ///
+    /// let path = Usage::<Name, PathBuf>::new(PathBuf::from("/"));
+    /// assert!(exists(&path));
+    ///
+    /// let boxed = Usage::<Name, Box<[u8]>>::new(Box::new([1, 2, 3]));
+    /// let _: &[u8] = AsRef::<[u8]>::as_ref(&boxed);
+    /// ```
+    #[cfg(feature = "std")]
+    [] String => std::path::Path,
+    #[cfg(feature = "alloc")]
+    [] String => str,
+    #[cfg(feature = "alloc")]
+    [] String => [u8],
+    #[cfg(feature = "std")]
+    [] String => std::ffi::OsStr,
+    #[cfg(feature = "std")]
+    [] std::path::PathBuf => std::path::Path,
+    #[cfg(feature = "std")]
+    [] std::path::PathBuf => std::ffi::OsStr,
+    #[cfg(feature = "std")]
+    [] std::ffi::OsString => std::ffi::OsStr,
+    #[cfg(feature = "std")]
+    [] std::ffi::OsString => std::path::Path,
+    #[cfg(feature = "alloc")]
+    [E] Vec<E> => [E],
+    [E, const N: usize] [E; N] => [E],
+    #[cfg(feature = "alloc")]
+    [E: ?Sized] Box<E> => E,
+    #[cfg(feature = "alloc")]
+    [E: ?Sized] alloc::rc::Rc<E> => E,
+    #[cfg(feature = "alloc")]
+    [E: ?Sized] alloc::sync::Arc<E> => E,
+);
+
+impl_as_mut_passthrough!(
+    /// As is `AsMut` passthrough:
+    /// ```
+    /// use usage::Usage;
+    ///
+    /// enum Tag {}
+    ///
+    /// fn zero(mut s: impl AsMut<[u8]>) {
+    ///     s.as_mut().fill(0);
+    /// }
+    ///
+    /// let mut bytes = Usage::<Tag, Vec<u8>>::new(vec![1, 2, 3]);
+    /// zero(&mut bytes);
+    /// assert_eq!(bytes.data, [0, 0, 0]);
+    /// ```
+    #[cfg(feature = "alloc")]
+    [] String => str,
+    #[cfg(feature = "alloc")]
+    [E] Vec<E> => [E],
+    [E, const N: usize] [E; N] => [E],
+    #[cfg(feature = "alloc")]
+    [E: ?Sized] Box<E> => E,
+);
+
+impl<U, T> Deref for Usage<U, T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.data
+    }
+}
+
+impl<U, T> DerefMut for Usage<U, T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.data
+    }
+}
+
+/// Forwards indexing to `T`, for use in generic code bounded on [`Index`]:
+/// ```
+/// use std::{collections::HashMap, ops::Index};
+/// use usage::Usage;
+///
+/// enum Tag {}
+///
+/// fn first<C: Index<usize, Output = i32>>(c: &C) -> i32 {
+///     c[0]
+/// }
+///
+/// let mut vec = Usage::<Tag, Vec<i32>>::new(vec![1, 2, 3]);
+/// assert_eq!(first(&vec), 1);
+/// vec[1] = 5;
+/// assert_eq!(vec[1], 5);
+///
+/// let mut map = Usage::<Tag, HashMap<String, i32>>::new(HashMap::new());
+/// map.insert("a".to_string(), 1);
+/// assert_eq!(Index::index(&map, "a"), &1);
+/// ```
+impl<U, T, I> Index<I> for Usage<U, T>
+where
+    T: Index<I>,
+{
+    type Output = T::Output;
+
+    fn index(&self, index: I) -> &Self::Output {
+        self.data.index(index)
+    }
+}
+
+impl<U, T, I> IndexMut<I> for Usage<U, T>
+where
+    T: IndexMut<I>,
+{
+    fn index_mut(&mut self, index: I) -> &mut Self::Output {
+        self.data.index_mut(index)
+    }
+}
+
+// Iteration traits
+/// Iterates over `T` by value, by reference, or by mutable reference:
+/// ```
+/// use usage::Usage;
+///
+/// enum Ids {}
+///
+/// let mut ids = Usage::<Ids, Vec<usize>>::new(vec![1, 2, 3]);
+///
+/// for id in &mut ids {
+///     *id *= 10;
+/// }
+///
+/// let mut borrowed = vec![];
+/// for id in &ids {
+///     borrowed.push(*id);
+/// }
+/// assert_eq!(borrowed, [10, 20, 30]);
+///
+/// let mut owned = vec![];
+/// for id in ids {
+///     owned.push(id);
+/// }
+/// assert_eq!(owned, [10, 20, 30]);
+/// ```
+///
+/// `Usage` cannot implement [`Iterator`] itself, as this would conflict with the above
+/// via the blanket `impl<I: Iterator> IntoIterator for I`.
+/// Instead, when `T` is an iterator, `into_iter` yields it directly so adapters remain available,
+/// and [`Iterator::next`] is reachable through [`DerefMut`]:
+/// ```
+/// use usage::Usage;
+///
+/// enum Parsed {}
+///
+/// let mut tokens = Usage::<Parsed, _>::new(vec!["a", "b", "c"].into_iter());
+/// assert_eq!(tokens.next(), Some("a"));
+///
+/// let rest: Vec<_> = tokens.into_iter().map(str::to_uppercase).collect();
+/// assert_eq!(rest, ["B", "C"]);
+/// ```
+///
+/// The same applies to [`DoubleEndedIterator`], [`ExactSizeIterator`]
+/// and [`FusedIterator`](std::iter::FusedIterator):
+/// ```
+/// use usage::Usage;
+///
+/// enum Parsed {}
+///
+/// let mut tokens = Usage::<Parsed, _>::new(vec![1, 2, 3, 4].into_iter());
+/// assert_eq!(tokens.len(), 4);
+/// assert_eq!(tokens.next_back(), Some(4));
+/// assert_eq!(tokens.len(), 3);
+///
+/// let reversed: Vec<_> = tokens.into_iter().rev().collect();
+/// assert_eq!(reversed, [3, 2, 1]);
+/// ```
+impl<U, T> IntoIterator for Usage<U, T>
+where
+    T: IntoIterator,
+{
+    type Item = T::Item;
+    type IntoIter = T::IntoIter;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.data.into_iter()
+    }
+}
+
+impl<'a, U, T> IntoIterator for &'a Usage<U, T>
+where
+    &'a T: IntoIterator,
+{
+    type Item = <&'a T as IntoIterator>::Item;
+    type IntoIter = <&'a T as IntoIterator>::IntoIter;
+
+    fn into_iter(self) -> Self::IntoIter {
+        (&self.data).into_iter()
+    }
+}
+
+impl<'a, U, T> IntoIterator for &'a mut Usage<U, T>
+where
+    &'a mut T: IntoIterator,
+{
+    type Item = <&'a mut T as IntoIterator>::Item;
+    type IntoIter = <&'a mut T as IntoIterator>::IntoIter;
+
+    fn into_iter(self) -> Self::IntoIter {
+        (&mut self.data).into_iter()
+    }
+}
+
+/// Forwards to `T`:
+/// ```
+/// use std::collections::HashSet;
+/// use usage::Usage;
+///
+/// enum Ids {}
+///
+/// let mut ids = Usage::<Ids, Vec<usize>>::new(vec![1, 2]);
+/// ids.extend([3, 4]);
+/// assert_eq!(ids.data, [1, 2, 3, 4]);
+///
+/// let mut set = Usage::<Ids, HashSet<usize>>::default();
+/// set.extend(vec![1, 2, 2, 3]);
+/// assert_eq!(set.len(), 3);
+/// ```
+impl<U, T, V> Extend<V> for Usage<U, T>
+where
+    T: Extend<V>,
+{
+    fn extend<I: IntoIterator<Item = V>>(&mut self, iter: I) {
+        self.data.extend(iter)
+    }
+}
+
+/// Folds over the inner values, preserving the tag:
+/// ```
+/// use usage::Usage;
+///
+/// enum Tag {}
+///
+/// let values: Vec<Usage<Tag, i32>> = vec![1, 2, 3, 4].into_iter().map(Usage::new).collect();
+///
+/// let sum: Usage<Tag, i32> = values.iter().sum();
+/// assert_eq!(sum.data, 10);
+/// let product: Usage<Tag, i32> = values.iter().product();
+/// assert_eq!(product.data, 24);
+///
+/// let sum: Usage<Tag, i32> = values.clone().into_iter().sum();
+/// assert_eq!(sum.data, 10);
+/// let product: Usage<Tag, i32> = values.into_iter().product();
+/// assert_eq!(product.data, 24);
+/// ```
+impl<U, T> Sum for Usage<U, T>
+where
+    T: Sum,
+{
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        U::as_usage(iter.map(|usage| usage.data).sum())
+    }
+}
+
+impl<'a, U, T> Sum<&'a Self> for Usage<U, T>
+where
+    T: Sum<&'a T>,
+{
+    fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        U::as_usage(iter.map(|usage| &usage.data).sum())
+    }
+}
+
+impl<U, T> Product for Usage<U, T>
+where
+    T: Product,
+{
+    fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+        U::as_usage(iter.map(|usage| usage.data).product())
+    }
+}
+
+impl<'a, U, T> Product<&'a Self> for Usage<U, T>
+where
+    T: Product<&'a T>,
+{
+    fn product<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        U::as_usage(iter.map(|usage| &usage.data).product())
+    }
+}
+
+// Operator traits
+macro_rules! impl_binary_ops {
+    ($($(#[$attr:meta])* $op:ident::$method:ident),* $(,)?) => {
+        $(
+            $(#[$attr])*
+            impl<U, T> core::ops::$op for Usage<U, T>
+            where
+                T: core::ops::$op,
+            {
+                type Output = Usage<U, T::Output>;
+
+                fn $method(self, rhs: Self) -> Self::Output {
+                    U::as_usage(self.data.$method(rhs.data))
+                }
+            }
+
+            impl<U, T> core::ops::$op<T> for Usage<U, T>
+            where
+                T: core::ops::$op,
+            {
+                type Output = Usage<U, T::Output>;
+
+                fn $method(self, rhs: T) -> Self::Output {
+                    U::as_usage(self.data.$method(rhs))
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! impl_binary_ref_ops {
+    ($($(#[$attr:meta])* $op:ident::$method:ident),* $(,)?) => {
+        $(
+            $(#[$attr])*
+            impl<'a, U, T> core::ops::$op<&'a Usage<U, T>> for Usage<U, T>
+            where
+                T: core::ops::$op<&'a T>,
+            {
+                type Output = Usage<U, <T as core::ops::$op<&'a T>>::Output>;
+
+                fn $method(self, rhs: &'a Usage<U, T>) -> Self::Output {
+                    U::as_usage(self.data.$method(&rhs.data))
+                }
+            }
+
+            impl<'a, U, T> core::ops::$op<Usage<U, T>> for &'a Usage<U, T>
+            where
+                &'a T: core::ops::$op<T>,
+            {
+                type Output = Usage<U, <&'a T as core::ops::$op<T>>::Output>;
+
+                fn $method(self, rhs: Usage<U, T>) -> Self::Output {
+                    U::as_usage((&self.data).$method(rhs.data))
+                }
+            }
+
+            impl<'a, 'b, U, T> core::ops::$op<&'b Usage<U, T>> for &'a Usage<U, T>
+            where
+                &'a T: core::ops::$op<&'b T>,
+            {
+                type Output = Usage<U, <&'a T as core::ops::$op<&'b T>>::Output>;
+
+                fn $method(self, rhs: &'b Usage<U, T>) -> Self::Output {
+                    U::as_usage((&self.data).$method(&rhs.data))
+                }
+            }
+        )*
+    };
+}
+
+impl_binary_ops!(
+    /// Arithmetic operators preserve the tag:
+    /// ```
+    /// use usage::Usage;
+    ///
+    /// enum Tag {}
+    ///
+    /// let a = Usage::<Tag, i32>::new(7);
+    /// let b = Usage::<Tag, i32>::new(2);
+    ///
+    /// let sum: Usage<Tag, i32> = a + b;
+    /// assert_eq!(sum.data, 9);
+    /// assert_eq!((a - b).data, 5);
+    /// assert_eq!((a * b).data, 14);
+    /// assert_eq!((a / b).data, 3);
+    /// assert_eq!((a % b).data, 1);
+    /// ```
+    ///
+    /// The right-hand side may also be a bare `T`:
+    /// ```
+    /// use usage::Usage;
+    ///
+    /// enum Meters {}
+    ///
+    /// let distance = Usage::<Meters, f64>::new(1.5);
+    ///
+    /// let scaled: Usage<Meters, f64> = distance * 2.0;
+    /// assert_eq!(scaled.data, 3.0);
+    /// assert_eq!((distance + 0.5).data, 2.0);
+    /// assert_eq!((distance / 3.0).data, 0.5);
+    /// ```
+    Add::add,
+    Sub::sub,
+    Mul::mul,
+    Div::div,
+    Rem::rem,
+);
+
+impl_binary_ref_ops!(
+    /// Operands may be borrowed, mirroring the primitive operator impls:
+    /// ```
+    /// use usage::Usage;
+    ///
+    /// enum Tag {}
+    ///
+    /// let a = Usage::<Tag, i32>::new(7);
+    /// let b = Usage::<Tag, i32>::new(2);
+    ///
+    /// let sum: Usage<Tag, i32> = a + b;
+    /// assert_eq!(sum.data, 9);
+    /// let sum: Usage<Tag, i32> = &a + b;
+    /// assert_eq!(sum.data, 9);
+    /// let sum: Usage<Tag, i32> = a + &b;
+    /// assert_eq!(sum.data, 9);
+    /// let sum: Usage<Tag, i32> = &a + &b;
+    /// assert_eq!(sum.data, 9);
+    ///
+    /// assert_eq!((&a - &b).data, 5);
+    /// assert_eq!((&a * &b).data, 14);
+    /// assert_eq!((&a / &b).data, 3);
+    /// assert_eq!((&a % &b).data, 1);
+    /// ```
+    Add::add,
+    Sub::sub,
+    Mul::mul,
+    Div::div,
+    Rem::rem,
+);
+
+macro_rules! impl_assign_ops {
+    ($($(#[$attr:meta])* $op:ident::$method:ident),* $(,)?) => {
+        $(
+            $(#[$attr])*
+            impl<U, T> core::ops::$op for Usage<U, T>
+            where
+                T: core::ops::$op,
+            {
+                fn $method(&mut self, rhs: Self) {
+                    self.data.$method(rhs.data)
+                }
+            }
+
+            impl<U, T> core::ops::$op<T> for Usage<U, T>
+            where
+                T: core::ops::$op,
+            {
+                fn $method(&mut self, rhs: T) {
+                    self.data.$method(rhs)
+                }
+            }
+        )*
+    };
+}
+
+impl_assign_ops!(
+    /// Arithmetic assignment operators accept either a same-tagged `Usage` or a bare `T`:
+    /// ```
+    /// use usage::Usage;
+    ///
+    /// enum Tag {}
+    ///
+    /// let mut total = Usage::<Tag, f64>::new(1.0);
+    ///
+    /// total += Usage::new(2.0);
+    /// assert_eq!(total.data, 3.0);
+    /// total -= 0.5;
+    /// assert_eq!(total.data, 2.5);
+    /// total *= Usage::new(4.0);
+    /// assert_eq!(total.data, 10.0);
+    /// total /= 4.0;
+    /// assert_eq!(total.data, 2.5);
+    /// total %= Usage::new(2.0);
+    /// assert_eq!(total.data, 0.5);
+    /// ```
+    AddAssign::add_assign,
+    SubAssign::sub_assign,
+    MulAssign::mul_assign,
+    DivAssign::div_assign,
+    RemAssign::rem_assign,
+);
+
+impl_binary_ops!(
+    /// Bitwise operators preserve the tag:
+    /// ```
+    /// use usage::Usage;
+    ///
+    /// enum Flags {}
+    ///
+    /// let read = Usage::<Flags, u8>::new(0b100);
+    /// let write = Usage::<Flags, u8>::new(0b010);
+    /// let read_write = Usage::<Flags, u8>::new(0b110);
+    ///
+    /// let combined: Usage<Flags, u8> = read | write;
+    /// assert_eq!(combined.data, 0b110);
+    /// assert_eq!((read_write & read).data, 0b100);
+    /// assert_eq!((read_write ^ write).data, 0b100);
+    ///
+    /// assert_eq!((&read | &write).data, 0b110);
+    /// assert_eq!((&read_write & read).data, 0b100);
+    /// assert_eq!((read_write ^ &write).data, 0b100);
+    ///
+    /// let mut flags = read;
+    /// flags |= write;
+    /// assert_eq!(flags.data, 0b110);
+    /// flags &= Usage::new(0b011);
+    /// assert_eq!(flags.data, 0b010);
+    /// flags ^= 0b001;
+    /// assert_eq!(flags.data, 0b011);
+    /// ```
+    BitAnd::bitand,
+    BitOr::bitor,
+    BitXor::bitxor,
+);
+
+impl_binary_ref_ops!(BitAnd::bitand, BitOr::bitor, BitXor::bitxor);
+
+impl_assign_ops!(
+    BitAndAssign::bitand_assign,
+    BitOrAssign::bitor_assign,
+    BitXorAssign::bitxor_assign,
+);
+
+macro_rules! impl_shift_ops {
+    ($($(#[$attr:meta])* $op:ident::$method:ident, $assign_op:ident::$assign_method:ident);* $(;)?) => {
+        $(
+            $(#[$attr])*
+            impl<U, T, R> core::ops::$op<R> for Usage<U, T>
+            where
+                T: core::ops::$op<R>,
+            {
+                type Output = Usage<U, T::Output>;
+
+                fn $method(self, rhs: R) -> Self::Output {
+                    U::as_usage(self.data.$method(rhs))
+                }
+            }
+
+            impl<'a, U, T, R> core::ops::$op<R> for &'a Usage<U, T>
+            where
+                &'a T: core::ops::$op<R>,
+            {
+                type Output = Usage<U, <&'a T as core::ops::$op<R>>::Output>;
+
+                fn $method(self, rhs: R) -> Self::Output {
+                    U::as_usage((&self.data).$method(rhs))
+                }
+            }
+
+            impl<U, T, R> core::ops::$assign_op<R> for Usage<U, T>
+            where
+                T: core::ops::$assign_op<R>,
+            {
+                fn $assign_method(&mut self, rhs: R) {
+                    self.data.$assign_method(rhs)
+                }
+            }
+        )*
+    };
+}
+
+impl_shift_ops!(
+    /// Shift operators take a bare shift amount, accept a borrowed operand and preserve the tag:
+    /// ```
+    /// use usage::Usage;
+    ///
+    /// enum Reg {}
+    ///
+    /// let reg = Usage::<Reg, u16>::new(0b0000_0011);
+    ///
+    /// let shifted: Usage<Reg, u16> = reg << 4_u32;
+    /// assert_eq!(shifted.data, 0b0011_0000);
+    /// assert_eq!((shifted >> 2_u32).data, 0b0000_1100);
+    /// assert_eq!((&reg << 1_u32).data, 0b0000_0110);
+    ///
+    /// let mut reg = reg;
+    /// reg <<= 8_u32;
+    /// assert_eq!(reg.data, 0b0000_0011_0000_0000);
+    /// reg >>= 9_u32;
+    /// assert_eq!(reg.data, 0b0000_0001);
+    /// ```
+    Shl::shl, ShlAssign::shl_assign;
+    Shr::shr, ShrAssign::shr_assign;
+);
+
+macro_rules! impl_unary_ops {
+    ($($(#[$attr:meta])* $op:ident::$method:ident),* $(,)?) => {
+        $(
+            $(#[$attr])*
+            impl<U, T> core::ops::$op for Usage<U, T>
+            where
+                T: core::ops::$op,
+            {
+                type Output = Usage<U, T::Output>;
+
+                fn $method(self) -> Self::Output {
+                    U::as_usage(self.data.$method())
+                }
+            }
+
+            impl<'a, U, T> core::ops::$op for &'a Usage<U, T>
+            where
+                &'a T: core::ops::$op,
+            {
+                type Output = Usage<U, <&'a T as core::ops::$op>::Output>;
+
+                fn $method(self) -> Self::Output {
+                    U::as_usage((&self.data).$method())
+                }
+            }
+        )*
+    };
+}
+
+impl_unary_ops!(
+    /// Unary operators preserve the tag, for both owned and borrowed operands:
+    /// ```
+    /// use usage::Usage;
+    ///
+    /// enum Celsius {}
+    /// enum Flags {}
+    ///
+    /// let temperature = Usage::<Celsius, f64>::new(4.5);
+    /// let negated: Usage<Celsius, f64> = -temperature;
+    /// assert_eq!(negated.data, -4.5);
+    ///
+    /// let negated: Usage<Celsius, f64> = -&temperature;
+    /// assert_eq!(negated.data, -4.5);
+    ///
+    /// let flags = Usage::<Flags, u32>::new(0x0000_ffff);
+    /// let inverted: Usage<Flags, u32> = !flags;
+    /// assert_eq!(inverted.data, 0xffff_0000);
+    /// assert_eq!((!&flags).data, 0xffff_0000);
+    /// ```
+    Neg::neg,
+    Not::not,
+);
+
+impl<U, T> Usage<U, T> {
+    /// Construct a new `Usage<U, T>` from `T`
+    ///
+    /// Unlike [`From`] and [`AsUsage::as_usage`], this is usable in `const` contexts:
+    /// ```
+    /// use usage::Usage;
+    ///
+    /// enum Window {}
+    ///
+    /// type WindowSize = Usage<Window, (u32, u32)>;
+    ///
+    /// const DEFAULT_SIZE: WindowSize = WindowSize::new((640, 480));
+    /// static MAX_SIZE: WindowSize = Usage::new((1920, 1080));
+    ///
+    /// assert_eq!(DEFAULT_SIZE.data, (640, 480));
+    /// assert_eq!(MAX_SIZE.data, (1920, 1080));
+    /// ```
+    pub const fn new(data: T) -> Self {
+        Usage {
+            data,
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Convert `Usage<T>` into `T` by value
+    /// ```
+    /// use usage::Usage;
+    ///
+    /// enum Tag {}
+    ///
+    /// const INNER: u32 = Usage::<Tag, u32>::new(42).into_inner();
+    /// assert_eq!(INNER, 42);
+    /// ```
+    pub const fn into_inner(self) -> T {
+        let this = ManuallyDrop::new(self);
+        // SAFETY: `Usage` is `#[repr(transparent)]` over `T`, and `this` is
+        // never dropped, so `data` is moved out exactly once.
+        // (Destructuring `self` directly is rejected in `const fn` for generic `T`.)
+        unsafe { core::ptr::read(&this as *const ManuallyDrop<Self> as *const T) }
+    }
+
+    /// Destructure `Usage<U, T>` into its data and tag
+    /// ```
+    /// use usage::Usage;
+    ///
+    /// enum Tag {}
+    ///
+    /// let usage = Usage::<Tag, Vec<u32>>::new(vec![1, 2, 3]);
+    /// let (data, tag) = usage.clone().into_parts();
+    /// assert_eq!(Usage::from_parts(data, tag), usage);
+    /// ```
+    pub const fn into_parts(self) -> (T, PhantomData<U>) {
+        (self.into_inner(), PhantomData)
+    }
+
+    /// Reconstruct `Usage<U, T>` from its data and tag
+    pub const fn from_parts(data: T, _: PhantomData<U>) -> Self {
+        Usage::new(data)
+    }
+
+    /// Reinterpret `&T` as `&Usage<U, T>` without copying or moving
+    /// ```
+    /// use usage::Usage;
+    ///
+    /// enum Ids {}
+    ///
+    /// let ids = vec![1, 2, 3];
+    /// let usage: &Usage<Ids, Vec<usize>> = Usage::from_ref(&ids);
+    /// assert!(std::ptr::eq(&**usage, &ids));
+    /// assert_eq!(*usage.as_slice(), [1, 2, 3]);
+    /// ```
+    pub fn from_ref(data: &T) -> &Self {
+        // SAFETY: `Usage` is `#[repr(transparent)]` over `T`,
+        // as its only other field is a zero-sized `PhantomData`.
+        // Both pointers therefore share the same layout and validity,
+        // and the returned reference inherits the lifetime of `data`.
+        unsafe { &*(data as *const T as *const Self) }
+    }
+
+    /// Reinterpret `&mut T` as `&mut Usage<U, T>` without copying or moving
+    /// ```
+    /// use usage::Usage;
+    ///
+    /// enum Ids {}
+    ///
+    /// let mut ids = vec![1, 2, 3];
+    /// let usage: &mut Usage<Ids, Vec<usize>> = Usage::from_mut(&mut ids);
+    /// usage.push(4);
+    /// assert_eq!(ids, [1, 2, 3, 4]);
+    /// ```
+    pub fn from_mut(data: &mut T) -> &mut Self {
+        // SAFETY: See `from_ref`; exclusivity is carried over from `data`.
+        unsafe { &mut *(data as *mut T as *mut Self) }
+    }
+
+    /// Reinterpret `&[T]` as `&[Usage<U, T>]` without copying
+    /// ```
+    /// use usage::Usage;
+    ///
+    /// enum Meters {}
+    ///
+    /// let distances = [1.5f32, 2.5, 4.0];
+    /// let tagged: &[Usage<Meters, f32>] = Usage::from_slice(&distances);
+    /// assert_eq!(tagged.len(), 3);
+    /// assert_eq!(tagged[1].data, 2.5);
+    ///
+    /// let inner: &[f32] = Usage::as_inner_slice(tagged);
+    /// assert!(std::ptr::eq(inner, &distances[..]));
+    /// assert_eq!(inner, [1.5, 2.5, 4.0]);
+    /// ```
+    pub fn from_slice(data: &[T]) -> &[Self] {
+        // SAFETY: See `from_ref`; identical element layout implies identical slice layout,
+        // so the length carries over unchanged.
+        unsafe { core::slice::from_raw_parts(data.as_ptr() as *const Self, data.len()) }
+    }
+
+    /// Reinterpret `&mut [T]` as `&mut [Usage<U, T>]` without copying
+    /// ```
+    /// use usage::Usage;
+    ///
+    /// enum Meters {}
+    ///
+    /// let mut distances = [1.5f32, 2.5, 4.0];
+    /// for distance in Usage::<Meters, f32>::from_mut_slice(&mut distances) {
+    ///     *distance *= 2.0;
+    /// }
+    /// assert_eq!(distances, [3.0, 5.0, 8.0]);
+    /// ```
+    pub fn from_mut_slice(data: &mut [T]) -> &mut [Self] {
+        // SAFETY: See `from_slice`; exclusivity is carried over from `data`.
+        unsafe { core::slice::from_raw_parts_mut(data.as_mut_ptr() as *mut Self, data.len()) }
+    }
+
+    /// Reinterpret `&[Usage<U, T>]` as `&[T]` without copying
+    /// ```
+    /// use usage::Usage;
+    ///
+    /// enum Meters {}
+    ///
+    /// let tagged = [Usage::<Meters, u32>::new(3), Usage::new(4)];
+    /// let inner: &[u32] = Usage::as_inner_slice(&tagged);
+    /// assert_eq!(inner.iter().sum::<u32>(), 7);
+    /// ```
+    pub fn as_inner_slice(data: &[Self]) -> &[T] {
+        // SAFETY: See `from_slice`.
+        unsafe { core::slice::from_raw_parts(data.as_ptr() as *const T, data.len()) }
+    }
+
+    /// Reinterpret `Box<T>` as `Box<Usage<U, T>>` without reallocating
+    /// ```
+    /// use usage::Usage;
+    ///
+    /// enum Ids {}
+    ///
+    /// let ids = Box::new(vec![1, 2, 3]);
+    /// let ptr: *const Vec<usize> = &*ids;
+    ///
+    /// let usage: Box<Usage<Ids, Vec<usize>>> = Usage::from_boxed(ids);
+    /// assert!(std::ptr::eq(&usage.data, ptr));
+    /// assert_eq!(usage.data, [1, 2, 3]);
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn from_boxed(data: Box<T>) -> Box<Self> {
+        // SAFETY: See `from_ref`; the allocation's layout is unchanged,
+        // so it may be freed through the new box type.
+        unsafe { Box::from_raw(Box::into_raw(data) as *mut Self) }
+    }
+
+    /// Reinterpret `Box<Usage<U, T>>` as `Box<T>` without reallocating
+    /// ```
+    /// use usage::Usage;
+    ///
+    /// enum Ids {}
+    ///
+    /// let usage = Box::new(Usage::<Ids, Vec<usize>>::new(vec![1, 2, 3]));
+    /// let ptr: *const Usage<Ids, Vec<usize>> = &*usage;
+    ///
+    /// let ids: Box<Vec<usize>> = usage.into_boxed_inner();
+    /// assert!(std::ptr::eq(&*ids, ptr as *const Vec<usize>));
+    /// assert_eq!(*ids, [1, 2, 3]);
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn into_boxed_inner(self: Box<Self>) -> Box<T> {
+        // SAFETY: See `from_boxed`.
+        unsafe { Box::from_raw(Box::into_raw(self) as *mut T) }
+    }
+
+    /// Map `Usage<U, T>` to `Usage<U, T2>` by applying a function to the inner value
+    /// ```
+    /// use usage::Usage;
+    ///
+    /// enum Celsius {}
+    ///
+    /// let temperature = Usage::<Celsius, f64>::new(21.6);
+    ///
+    /// let doubled: Usage<Celsius, f64> = temperature.map(|t| t * 2.0);
+    /// assert_eq!(doubled.data, 43.2);
+    ///
+    /// let rounded: Usage<Celsius, i64> = temperature.map(|t| t.round() as i64);
+    /// assert_eq!(rounded.data, 22);
+    /// ```
+    pub fn map<T2>(self, f: impl FnOnce(T) -> T2) -> Usage<U, T2> {
+        U::as_usage(f(self.data))
+    }
+
+    /// Map `Usage<U, T>` to `Result<Usage<U, T2>, E>` by applying a fallible function to the inner value
+    /// ```
+    /// use usage::Usage;
+    ///
+    /// enum Port {}
+    ///
+    /// let port = Usage::<Port, &str>::new("8080").try_map(str::parse::<u16>);
+    /// let port: Usage<Port, u16> = port.unwrap();
+    /// assert_eq!(port.data, 8080);
+    ///
+    /// let port = Usage::<Port, &str>::new("http").try_map(str::parse::<u16>);
+    /// assert_eq!(port.unwrap_err(), "http".parse::<u16>().unwrap_err());
+    /// ```
+    pub fn try_map<T2, E>(self, f: impl FnOnce(T) -> Result<T2, E>) -> Result<Usage<U, T2>, E> {
+        f(self.data).map(U::as_usage)
+    }
+
+    /// Convert `&Usage<U, T>` into `Usage<U, &T>`, mirroring [`Option::as_ref`]
+    ///
+    /// ```
+    /// use usage::Usage;
+    ///
+    /// enum Tag {}
+    ///
+    /// let usage = Usage::<Tag, String>::new("data".into());
+    /// let view: Usage<Tag, &String> = usage.as_usage_ref();
+    /// assert_eq!(view.data, "data");
+    /// ```
+    pub fn as_usage_ref(&self) -> Usage<U, &T> {
+        U::as_usage(&self.data)
+    }
+
+    /// Convert `&mut Usage<U, T>` into `Usage<U, &mut T>`, mirroring [`Option::as_mut`]
+    ///
+    /// ```
+    /// use usage::Usage;
+    ///
+    /// enum Tag {}
+    ///
+    /// let mut usage = Usage::<Tag, String>::new("data".into());
+    /// let view: Usage<Tag, &mut String> = usage.as_usage_mut();
+    /// view.data.push('!');
+    /// assert_eq!(usage.data, "data!");
+    /// ```
+    pub fn as_usage_mut(&mut self) -> Usage<U, &mut T> {
+        U::as_usage(&mut self.data)
+    }
+
+    /// Map `&Usage<U, T>` to `Usage<U, T2>` by applying a function to a reference to the inner value
+    /// ```
+    /// use usage::Usage;
+    ///
+    /// enum Tag {}
+    ///
+    /// let usage = Usage::<Tag, String>::new("data".into());
+    /// let len: Usage<Tag, usize> = usage.map_ref(String::len);
+    /// assert_eq!(len.data, 4);
+    /// assert_eq!(usage.data, "data");
+    /// ```
+    pub fn map_ref<T2>(&self, f: impl FnOnce(&T) -> T2) -> Usage<U, T2> {
+        U::as_usage(f(&self.data))
+    }
+
+    /// Convert `&Usage<U, T>` into `Usage<U, &T::Target>`, mirroring [`Option::as_deref`]
+    /// ```
+    /// use usage::Usage;
+    ///
+    /// enum Tag {}
+    ///
+    /// let name = Usage::<Tag, String>::new("ferris".into());
+    /// let view: Usage<Tag, &str> = name.as_deref();
+    /// assert_eq!(view.data, "ferris");
+    ///
+    /// let bytes = Usage::<Tag, Vec<u8>>::new(vec![1, 2, 3]);
+    /// let view: Usage<Tag, &[u8]> = bytes.as_deref();
+    /// assert_eq!(view.data, [1, 2, 3]);
+    /// ```
+    pub fn as_deref(&self) -> Usage<U, &T::Target>
+    where
+        T: Deref,
+    {
+        U::as_usage(self.data.deref())
+    }
+
+    /// Convert `&mut Usage<U, T>` into `Usage<U, &mut T::Target>`, mirroring [`Option::as_deref_mut`]
+    /// ```
+    /// use usage::Usage;
+    ///
+    /// enum Tag {}
+    ///
+    /// let mut bytes = Usage::<Tag, Vec<u8>>::new(vec![1, 2, 3]);
+    /// let view: Usage<Tag, &mut [u8]> = bytes.as_deref_mut();
+    /// view.data.reverse();
+    /// assert_eq!(bytes.data, [3, 2, 1]);
+    /// ```
+    pub fn as_deref_mut(&mut self) -> Usage<U, &mut T::Target>
+    where
+        T: DerefMut,
+    {
+        U::as_usage(self.data.deref_mut())
+    }
+
+    /// Project `Pin<&mut Usage<U, T>>` to `Pin<&mut T>`, as `data` is structurally pinned
+    /// ```
+    /// use std::future::Future;
+    /// use std::pin::{pin, Pin};
+    /// use std::task::{Context, Poll, Waker};
+    /// use usage::Usage;
+    ///
+    /// enum Job {}
+    ///
+    /// let mut job = pin!(Usage::<Job, _>::new(async { 7 }));
+    ///
+    /// let inner: Pin<&mut _> = job.as_mut().project_pin();
+    /// let mut cx = Context::from_waker(Waker::noop());
+    /// assert_eq!(inner.poll(&mut cx), Poll::Ready(7));
+    /// ```
+    pub fn project_pin(self: Pin<&mut Self>) -> Pin<&mut T> {
+        // SAFETY: `Usage` is `repr(transparent)` over `data`, has no `Drop` impl,
+        // and is only `Unpin` when `T` is, so `data` is never moved out of a pinned `Usage`.
+        unsafe { self.map_unchecked_mut(|usage| &mut usage.data) }
+    }
+
+    /// Project `Pin<&Usage<U, T>>` to `Pin<&T>`
+    /// ```
+    /// use std::pin::{pin, Pin};
+    /// use usage::Usage;
+    ///
+    /// enum Tag {}
+    ///
+    /// let usage = pin!(Usage::<Tag, u32>::new(3));
+    /// let inner: Pin<&u32> = usage.as_ref().project_pin_ref();
+    /// assert_eq!(*inner, 3);
+    /// ```
+    pub fn project_pin_ref(self: Pin<&Self>) -> Pin<&T> {
+        // SAFETY: See `project_pin`.
+        unsafe { self.map_unchecked(|usage| &usage.data) }
+    }
+
+    /// Convert `Pin<&mut Usage<U, T>>` into `Usage<U, Pin<&mut T>>`, keeping the tag
+    /// ```
+    /// use std::future::Future;
+    /// use std::pin::{pin, Pin};
+    /// use std::task::{Context, Poll, Waker};
+    /// use usage::Usage;
+    ///
+    /// enum Job {}
+    ///
+    /// fn drive(mut job: Usage<Job, Pin<&mut impl Future<Output = u32>>>) -> Poll<u32> {
+    ///     job.data.as_mut().poll(&mut Context::from_waker(Waker::noop()))
+    /// }
+    ///
+    /// let mut job = pin!(Usage::<Job, _>::new(async { 7 }));
+    /// assert_eq!(drive(job.as_mut().map_pin()), Poll::Ready(7));
+    /// ```
+    pub fn map_pin(self: Pin<&mut Self>) -> Usage<U, Pin<&mut T>> {
+        U::as_usage(self.project_pin())
+    }
+
+    /// Convert `Pin<&Usage<U, T>>` into `Usage<U, Pin<&T>>`, keeping the tag
+    /// ```
+    /// use std::pin::{pin, Pin};
+    /// use usage::Usage;
+    ///
+    /// enum Tag {}
+    ///
+    /// let usage = pin!(Usage::<Tag, u32>::new(3));
+    /// let view: Usage<Tag, Pin<&u32>> = usage.as_ref().map_pin_ref();
+    /// assert_eq!(*view.data, 3);
+    /// ```
+    pub fn map_pin_ref(self: Pin<&Self>) -> Usage<U, Pin<&T>> {
+        U::as_usage(self.project_pin_ref())
+    }
+
+    /// Clone the data behind `T` into its owned form, e.g. `Usage<U, &str>` into `Usage<U, String>`
+    ///
+    /// This goes through [`Deref`] rather than requiring `T: ToOwned` directly,
+    /// as borrowed `T` such as `&[E]` would otherwise produce another reference.
+    /// ```
+    /// use std::borrow::Cow;
+    /// use usage::Usage;
+    ///
+    /// enum Ids {}
+    ///
+    /// let ids = [1, 2, 3];
+    /// let borrowed = Usage::<Ids, &[usize]>::new(&ids);
+    /// let owned: Usage<Ids, Vec<usize>> = borrowed.to_owned_usage();
+    /// assert_eq!(owned.data, [1, 2, 3]);
+    ///
+    /// let name = Usage::<Ids, &str>::new("ferris");
+    /// let name: Usage<Ids, String> = name.to_owned_usage();
+    /// assert_eq!(name.data, "ferris");
+    ///
+    /// let cow = Usage::<Ids, Cow<[usize]>>::new(Cow::Borrowed(&ids));
+    /// assert_eq!(cow.len(), 3);
+    /// assert_eq!(cow.to_owned_usage().data, [1, 2, 3]);
+    ///
+    /// let owned: Usage<Ids, Vec<usize>> = cow.map(Cow::into_owned);
+    /// assert_eq!(owned.data, [1, 2, 3]);
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn to_owned_usage(&self) -> Usage<U, <T::Target as alloc::borrow::ToOwned>::Owned>
+    where
+        T: Deref,
+        T::Target: alloc::borrow::ToOwned,
+    {
+        U::as_usage(alloc::borrow::ToOwned::to_owned(self.data.deref()))
+    }
+
+    /// Reinterpret `Usage<U, T>` as `Usage<U2, T>`
+    ///
+    /// This deliberately bypasses the distinction between tags,
+    /// and is intended as an explicit escape hatch for cases like marking data as validated.
+    /// ```
+    /// use usage::Usage;
+    ///
+    /// enum Raw {}
+    /// enum Validated {}
+    ///
+    /// let raw = Usage::<Raw, String>::new("input".into());
+    /// let validated: Usage<Validated, String> = raw.retag();
+    /// let raw: Usage<Raw, String> = validated.retag();
+    /// assert_eq!(raw.data, "input");
+    /// ```
+    pub fn retag<U2>(self) -> Usage<U2, T> {
+        U2::as_usage(self.data)
+    }
+
+    /// Reinterpret `&Usage<U, T>` as `&Usage<U2, T>`
+    ///
+    /// As with [`retag`](Usage::retag), this bypasses the distinction between tags.
+    /// ```
+    /// use usage::Usage;
+    ///
+    /// enum Raw {}
+    /// enum Validated {}
+    ///
+    /// let raw = Usage::<Raw, String>::new("input".into());
+    /// let validated: &Usage<Validated, String> = raw.retag_ref();
+    /// assert!(std::ptr::eq(&validated.data, &raw.data));
+    /// ```
+    pub fn retag_ref<U2>(&self) -> &Usage<U2, T> {
+        Usage::from_ref(&self.data)
+    }
+
+    /// Reinterpret `&mut Usage<U, T>` as `&mut Usage<U2, T>`
+    ///
+    /// As with [`retag`](Usage::retag), this bypasses the distinction between tags.
+    /// ```
+    /// use usage::Usage;
+    ///
+    /// enum Raw {}
+    /// enum Validated {}
+    ///
+    /// let mut raw = Usage::<Raw, String>::new("input".into());
+    /// let validated: &mut Usage<Validated, String> = raw.retag_mut();
+    /// validated.push('!');
+    /// assert_eq!(raw.data, "input!");
+    /// ```
+    pub fn retag_mut<U2>(&mut self) -> &mut Usage<U2, T> {
+        Usage::from_mut(&mut self.data)
+    }
+
+    /// Return the type name of the tag `U`, for diagnostic purposes
+    /// ```
+    /// use usage::Usage;
+    ///
+    /// enum Window {}
+    ///
+    /// let size = Usage::<Window, (u32, u32)>::new((640, 480));
+    /// assert!(size.tag_name().ends_with("Window"));
+    /// ```
+    pub fn tag_name(&self) -> &'static str {
+        core::any::type_name::<U>()
+    }
+
+    /// Return the [`TypeId`](std::any::TypeId) of the tag `U`, for diagnostic purposes
+    /// ```
+    /// use std::any::TypeId;
+    /// use usage::Usage;
+    ///
+    /// enum Window {}
+    /// enum Surface {}
+    ///
+    /// assert_eq!(Usage::<Window, u32>::tag_type_id(), TypeId::of::<Window>());
+    /// assert_ne!(Usage::<Window, u32>::tag_type_id(), Usage::<Surface, u32>::tag_type_id());
+    /// ```
+    pub fn tag_type_id() -> core::any::TypeId
+    where
+        U: 'static,
+    {
+        core::any::TypeId::of::<U>()
+    }
+}
+
+impl<U, T> Usage<U, Option<T>> {
+    /// Convert `Usage<U, Option<T>>` into `Option<Usage<U, T>>`, mirroring [`Option::transpose`]
+    /// ```
+    /// use usage::Usage;
+    ///
+    /// enum Tag {}
+    ///
+    /// let some = Usage::<Tag, Option<i32>>::new(Some(1));
+    /// let some: Option<Usage<Tag, i32>> = some.transpose();
+    /// assert_eq!(some.unwrap().data, 1);
+    ///
+    /// let none = Usage::<Tag, Option<i32>>::new(None);
+    /// assert!(none.transpose().is_none());
+    /// ```
+    pub fn transpose(self) -> Option<Usage<U, T>> {
+        self.data.map(U::as_usage)
+    }
+}
+
+impl<U, T, E> Usage<U, Result<T, E>> {
+    /// Convert `Usage<U, Result<T, E>>` into `Result<Usage<U, T>, E>`, mirroring [`Result::transpose`]
+    /// ```
+    /// use usage::Usage;
+    ///
+    /// enum Tag {}
+    ///
+    /// let ok = Usage::<Tag, Result<i32, String>>::new(Ok(1));
+    /// let ok: Result<Usage<Tag, i32>, String> = ok.transpose();
+    /// assert_eq!(ok.unwrap().data, 1);
+    ///
+    /// let err = Usage::<Tag, Result<i32, String>>::new(Err("error".into()));
+    /// assert_eq!(err.transpose().unwrap_err(), "error");
+    /// ```
+    pub fn transpose(self) -> Result<Usage<U, T>, E> {
+        self.data.map(U::as_usage)
+    }
+}
+
+impl<U, T> Usage<U, Usage<U, T>> {
+    /// Collapse `Usage<U, Usage<U, T>>` into `Usage<U, T>`
+    /// ```
+    /// use usage::Usage;
+    ///
+    /// enum Tag {}
+    ///
+    /// let nested = Usage::<Tag, i32>::new(1).map(|i| Usage::<Tag, i32>::new(i + 1));
+    /// let flat: Usage<Tag, i32> = nested.flatten();
+    /// assert_eq!(flat.data, 2);
+    /// ```
+    pub fn flatten(self) -> Usage<U, T> {
         self.data
     }
 }
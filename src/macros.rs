@@ -0,0 +1,89 @@
+/// Declares tag types alongside [`Usage`](crate::Usage) aliases over them.
+///
+/// Each `Alias => Tag: T` declaration expands to an uninhabited `enum Tag {}`
+/// and a `type Alias = Usage<Tag, T>`, both with the given visibility.
+/// Construction goes through the alias, as with any other `Usage`:
+/// ```
+/// usage::define_usage! {
+///     pub WindowSize => Window: (u32, u32);
+///     pub SurfaceSize => Surface: (u32, u32);
+///     /// Attributes are applied to the alias.
+///     TextureSize => Texture: (u32, u32);
+/// }
+///
+/// let window = WindowSize::new((640, 480));
+/// let surface: SurfaceSize = (*window).into();
+///
+/// assert_eq!(window.data, surface.data);
+/// assert_eq!(TextureSize::default().data, (0, 0));
+/// ```
+///
+/// The generated aliases are distinct types:
+/// ```compile_fail
+/// usage::define_usage! {
+///     WindowSize => Window: (u32, u32);
+///     SurfaceSize => Surface: (u32, u32);
+/// }
+///
+/// let surface: SurfaceSize = WindowSize::new((640, 480));
+/// ```
+#[macro_export]
+macro_rules! define_usage {
+    ($($(#[$attr:meta])* $vis:vis $alias:ident => $tag:ident: $ty:ty);* $(;)?) => {
+        $(
+            $vis enum $tag {}
+
+            $(#[$attr])*
+            $vis type $alias = $crate::Usage<$tag, $ty>;
+        )*
+    };
+}
+
+/// Declares many tag types sharing a single inner type.
+///
+/// `Alias = T; Tags...` expands to a generic `type Alias<U> = Usage<U, T>`
+/// and an uninhabited `enum` for each tag, all with the given visibility.
+/// A tag may also be written `Tag => TagAlias` to emit a concrete `type TagAlias = Alias<Tag>`:
+/// ```
+/// usage::usage_aliases! {
+///     pub Size = (u32, u32);
+///     Window => WindowSize,
+///     Surface => SurfaceSize,
+///     Texture,
+/// }
+///
+/// let window = WindowSize::new((640, 480));
+/// let surface = SurfaceSize::new((320, 240));
+/// let texture: Size<Texture> = Size::new((64, 64));
+///
+/// assert_eq!(window.data.0, 640);
+/// assert_eq!(surface.data.1, 240);
+/// assert_eq!(texture.data, (64, 64));
+/// ```
+///
+/// As with [`define_usage`], each tag yields a distinct type:
+/// ```compile_fail
+/// usage::usage_aliases! {
+///     Size = (u32, u32);
+///     Window,
+///     Surface,
+/// }
+///
+/// let surface: Size<Surface> = Size::<Window>::new((640, 480));
+/// ```
+#[macro_export]
+macro_rules! usage_aliases {
+    (
+        $(#[$attr:meta])* $vis:vis $alias:ident = $ty:ty;
+        $($tag:ident $(=> $tag_alias:ident)?),* $(,)?
+    ) => {
+        $(#[$attr])*
+        $vis type $alias<U> = $crate::Usage<U, $ty>;
+
+        $(
+            $vis enum $tag {}
+
+            $($vis type $tag_alias = $alias<$tag>;)?
+        )*
+    };
+}
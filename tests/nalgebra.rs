@@ -0,0 +1,38 @@
+//! Tagged scalars as `nalgebra` elements, relying on its blanket `Scalar` and `Closed*` impls.
+
+#![cfg(feature = "num-traits")]
+
+use nalgebra::{ClosedAddAssign, ClosedMulAssign, Matrix2, Scalar, Vector2, Vector3};
+use usage::Usage;
+
+enum Meters {}
+
+type M = Usage<Meters, f64>;
+
+fn assert_element<T: Scalar + ClosedAddAssign + ClosedMulAssign>() {}
+
+#[test]
+fn tagged_scalars_are_elements() {
+    assert_element::<M>();
+    assert_element::<Usage<Meters, i32>>();
+}
+
+#[test]
+fn vector_arithmetic_keeps_the_tag() {
+    let a = Vector2::new(M::new(1.0), M::new(2.0));
+    let b = Vector2::new(M::new(0.5), M::new(4.0));
+
+    assert_eq!(a + b, Vector2::new(M::new(1.5), M::new(6.0)));
+    assert_eq!(a * M::new(2.0), Vector2::new(M::new(2.0), M::new(4.0)));
+    assert_eq!(a.dot(&b), M::new(8.5));
+}
+
+#[test]
+fn matrix_products_and_constructors() {
+    let a = Vector2::new(M::new(1.0), M::new(2.0));
+    let swap = Matrix2::new(M::new(0.0), M::new(1.0), M::new(1.0), M::new(0.0));
+
+    assert_eq!(swap * a, Vector2::new(M::new(2.0), M::new(1.0)));
+    assert_eq!(Matrix2::<M>::identity() * a, a);
+    assert_eq!(Vector3::<M>::zeros().sum(), M::new(0.0));
+}
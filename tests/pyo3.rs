@@ -0,0 +1,31 @@
+//! Round-trips tagged values through an embedded interpreter.
+
+use pyo3::{prelude::*, types::PyInt};
+use usage::Usage;
+
+enum UserId {}
+
+#[test]
+fn round_trip() {
+    Python::initialize();
+
+    Python::attach(|py| {
+        let id = Usage::<UserId, i64>::new(42);
+
+        let object = id.into_pyobject(py).unwrap();
+        assert!(object.is_instance_of::<PyInt>());
+        assert_eq!(object.extract::<i64>().unwrap(), 42);
+
+        let by_ref = (&id).into_pyobject(py).unwrap();
+        assert_eq!(by_ref.extract::<i64>().unwrap(), 42);
+
+        let parsed: Usage<UserId, i64> = py.eval(c"6 * 7", None, None).unwrap().extract().unwrap();
+        assert_eq!(parsed, id);
+
+        assert!(py
+            .eval(c"'42'", None, None)
+            .unwrap()
+            .extract::<Usage<UserId, i64>>()
+            .is_err());
+    });
+}
@@ -0,0 +1,34 @@
+//! Binds and reads tagged values against an in-memory database.
+
+use rusqlite::Connection;
+use usage::Usage;
+
+enum RowId {}
+
+#[test]
+fn round_trip() {
+    let conn = Connection::open_in_memory().unwrap();
+    conn.execute("CREATE TABLE users (id INTEGER PRIMARY KEY, name TEXT)", ())
+        .unwrap();
+
+    let id = Usage::<RowId, i64>::new(7);
+    conn.execute(
+        "INSERT INTO users (id, name) VALUES (?1, ?2)",
+        (id, "ferris"),
+    )
+    .unwrap();
+
+    let found: Usage<RowId, i64> = conn
+        .query_row("SELECT id FROM users WHERE name = ?1", ["ferris"], |row| {
+            row.get(0)
+        })
+        .unwrap();
+    assert_eq!(found, id);
+
+    let name: String = conn
+        .query_row("SELECT name FROM users WHERE id = ?1", [id], |row| {
+            row.get(0)
+        })
+        .unwrap();
+    assert_eq!(name, "ferris");
+}
@@ -0,0 +1,147 @@
+//! `tracing::Value` is sealed, so tagged fields are recorded through the routes
+//! documented at the crate root; these tests pin each of them down.
+//!
+//! The `valuable` route needs `tracing_unstable`:
+//! `RUSTFLAGS="--cfg tracing_unstable" cargo test --features valuable --test tracing`.
+
+use std::{
+    fmt,
+    sync::{Arc, Mutex},
+};
+
+use tracing::{
+    field::{Field, Visit},
+    span, Event, Metadata, Subscriber,
+};
+use usage::Usage;
+
+#[derive(Debug, PartialEq)]
+enum Recorded {
+    U64(u64),
+    Str(String),
+    Debug(String),
+    #[cfg(all(tracing_unstable, feature = "valuable"))]
+    Value(String),
+}
+
+#[derive(Clone, Default)]
+struct Collector(Arc<Mutex<Vec<(&'static str, Recorded)>>>);
+
+impl Collector {
+    fn record(&self, field: &Field, value: Recorded) {
+        self.0.lock().unwrap().push((field.name(), value));
+    }
+
+    fn take(&self) -> Vec<(&'static str, Recorded)> {
+        std::mem::take(&mut self.0.lock().unwrap())
+    }
+}
+
+impl Visit for Collector {
+    fn record_u64(&mut self, field: &Field, value: u64) {
+        self.record(field, Recorded::U64(value));
+    }
+
+    fn record_str(&mut self, field: &Field, value: &str) {
+        self.record(field, Recorded::Str(value.into()));
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        self.record(field, Recorded::Debug(format!("{:?}", value)));
+    }
+
+    #[cfg(all(tracing_unstable, feature = "valuable"))]
+    fn record_value(&mut self, field: &Field, value: valuable::Value<'_>) {
+        self.record(field, Recorded::Value(format!("{:?}", value)));
+    }
+}
+
+impl Subscriber for Collector {
+    fn enabled(&self, _: &Metadata<'_>) -> bool {
+        true
+    }
+
+    fn new_span(&self, span: &span::Attributes<'_>) -> span::Id {
+        span.record(&mut self.clone());
+        span::Id::from_u64(1)
+    }
+
+    fn record(&self, _: &span::Id, values: &span::Record<'_>) {
+        values.record(&mut self.clone());
+    }
+
+    fn record_follows_from(&self, _: &span::Id, _: &span::Id) {}
+
+    fn event(&self, event: &Event<'_>) {
+        event.record(&mut self.clone());
+    }
+
+    fn enter(&self, _: &span::Id) {}
+
+    fn exit(&self, _: &span::Id) {}
+}
+
+enum RequestId {}
+
+fn recorded(log: impl FnOnce()) -> Vec<(&'static str, Recorded)> {
+    let collector = Collector::default();
+    tracing::subscriber::with_default(collector.clone(), log);
+    collector
+        .take()
+        .into_iter()
+        .filter(|(name, _)| *name != "message")
+        .collect()
+}
+
+#[test]
+fn display_sigil_records_inner_value() {
+    let id = Usage::<RequestId, u64>::new(42);
+
+    assert_eq!(
+        recorded(|| tracing::info!(id = %id)),
+        [("id", Recorded::Debug(42.to_string()))]
+    );
+}
+
+#[test]
+fn deref_records_inner_value() {
+    let id = Usage::<RequestId, u64>::new(42);
+    let name = Usage::<RequestId, &str>::new("checkout");
+
+    assert_eq!(
+        recorded(|| tracing::info!(id = *id, name = *name)),
+        [
+            ("id", Recorded::U64(42)),
+            ("name", Recorded::Str("checkout".into()))
+        ]
+    );
+}
+
+#[test]
+fn span_fields_record_inner_value() {
+    let id = Usage::<RequestId, u64>::new(42);
+
+    assert_eq!(
+        recorded(|| {
+            let span = tracing::info_span!("request", id = *id, tag = tracing::field::Empty);
+            span.record("tag", tracing::field::display(&id));
+        }),
+        [
+            ("id", Recorded::U64(42)),
+            ("tag", Recorded::Debug(42.to_string()))
+        ]
+    );
+}
+
+#[cfg(all(tracing_unstable, feature = "valuable"))]
+#[test]
+fn valuable_records_inner_value() {
+    use valuable::Valuable;
+
+    let id = Usage::<RequestId, u64>::new(42);
+
+    assert_eq!(
+        recorded(|| tracing::info!(id = tracing::field::valuable(&id))),
+        [("id", Recorded::Value(format!("{:?}", 42u64.as_value())))]
+    );
+}